@@ -0,0 +1,133 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    U128(u128),
+    Text(String),
+    /// A picture to be read by eye, one string per row.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn grid<S: ToString>(rows: impl IntoIterator<Item = S>) -> Self {
+        Self::Grid(rows.into_iter().map(|row| row.to_string()).collect())
+    }
+
+    /// The answer as a number, if it is one (including text that holds a number).
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Unsigned(val) => Some((*val).into()),
+            Answer::Signed(val) => Some((*val).into()),
+            Answer::U128(val) => (*val).try_into().ok(),
+            Answer::Text(text) => text.trim().parse().ok(),
+            Answer::Grid(_) => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(val) => write!(f, "{}", val),
+            Answer::Signed(val) => write!(f, "{}", val),
+            Answer::U128(val) => write!(f, "{}", val),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Reads back an answer written with `Display`, choosing the narrowest variant that fits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_end_matches('\n');
+        if s.contains('\n') {
+            return Ok(Self::grid(s.lines()));
+        }
+        if let Ok(val) = s.parse() {
+            return Ok(Self::Unsigned(val));
+        }
+        if let Ok(val) = s.parse() {
+            return Ok(Self::Signed(val));
+        }
+        if let Ok(val) = s.parse() {
+            return Ok(Self::U128(val));
+        }
+        Ok(Self::Text(s.to_string()))
+    }
+}
+
+/// Numbers compare by value whatever their width or sign; anything else compares as text.
+impl<T: Clone + Into<Answer>> PartialEq<T> for Answer {
+    fn eq(&self, other: &T) -> bool {
+        let other: Answer = other.clone().into();
+        match (self.as_integer(), other.as_integer()) {
+            (Some(a), Some(b)) => a == b,
+            _ => {
+                let (a, b) = (self.to_string(), other.to_string());
+                a == b
+            }
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+macro_rules! answer_from {
+    ($variant:ident as $target:ty: $($source:ty),*) => {
+        $(impl From<$source> for Answer {
+            fn from(value: $source) -> Self {
+                Self::$variant(value as $target)
+            }
+        })*
+    };
+}
+
+answer_from!(Unsigned as u64: u8, u16, u32, u64, usize);
+answer_from!(Signed as i64: i8, i16, i32, i64, isize);
+answer_from!(U128 as u128: u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        value.parse().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_narrowest_variant() {
+        assert!(matches!("41".parse(), Ok(Answer::Unsigned(41))));
+        assert!(matches!("-3".parse(), Ok(Answer::Signed(-3))));
+        assert!(matches!(
+            "340282366920938463463374607431768211455".parse(),
+            Ok(Answer::U128(u128::MAX))
+        ));
+        assert!(matches!("4,6,3".parse(), Ok(Answer::Text(_))));
+        assert!(matches!("#.\n.#\n".parse(), Ok(Answer::Grid(rows)) if rows.len() == 2));
+    }
+
+    #[test]
+    fn compares_across_variants() {
+        assert_eq!(Answer::from(161i32), 161usize);
+        assert_eq!(Answer::from(12usize), "12");
+        assert_eq!(Answer::from("4,6,3,5".to_string()), "4,6,3,5");
+        assert_ne!(Answer::from(-1i32), 1usize);
+        assert_eq!(Answer::grid(["#.", ".#"]), "#.\n.#");
+    }
+}
@@ -0,0 +1,239 @@
+use crate::*;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub const BASELINE_FILE: &str = "input/bench.json";
+
+/// Summary of repeated timings, all in nanoseconds so they round-trip through JSON exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+    pub stddev: u64,
+    pub p95: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+
+        let n = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        let median = if n % 2 == 1 {
+            nanos[n / 2]
+        } else {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0
+        };
+        // Nearest-rank percentile
+        let p95 = nanos[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+
+        Self {
+            runs: n,
+            min: nanos[0] as u64,
+            median: median as u64,
+            mean: mean as u64,
+            stddev: variance.sqrt() as u64,
+            p95: p95 as u64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let d = Duration::from_nanos;
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?} ± {:?}, p95 {:?} ({} runs)",
+            d(self.min),
+            d(self.median),
+            d(self.mean),
+            d(self.stddev),
+            d(self.p95),
+            self.runs
+        )
+    }
+}
+
+/// What to time: the number of untimed warm-up runs, then the number of timed runs.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+/// Timings for one day. Parsing is timed from an in-memory copy of the input, so file I/O
+/// isn't counted, and each part is timed against a single parsed input.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayBench {
+    fn entries(&self) -> [(&'static str, Option<&Stats>); 3] {
+        [
+            ("Parse", self.parse.as_ref()),
+            ("Part 1", self.part1.as_ref()),
+            ("Part 2", self.part2.as_ref()),
+        ]
+    }
+
+    /// Every measurement whose median grew by more than `threshold` (a fraction, 0.1 = 10%)
+    /// over the baseline's, with the relative change.
+    pub fn regressions(&self, baseline: &DayBench, threshold: f64) -> Vec<(&'static str, f64)> {
+        self.entries()
+            .into_iter()
+            .zip(baseline.entries())
+            .filter_map(|((name, current), (_, base))| {
+                let (current, base) = (current?, base?);
+                let change = current.median as f64 / base.median.max(1) as f64 - 1.0;
+                (change > threshold).then_some((name, change))
+            })
+            .collect()
+    }
+}
+
+fn time<T>(options: BenchOptions, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..options.warmup {
+        f()?;
+    }
+    let samples = (0..options.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f()?);
+            Ok(start.elapsed())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Stats::from_samples(&samples))
+}
+
+/// Times parsing and the selected parts of a day against its input.
+pub fn bench(
+    solution: &dyn AnySolution,
+    parts: &[Part],
+    options: BenchOptions,
+) -> Result<DayBench> {
+    let text = InputSource::default_for(solution.day()).read(solution)?;
+    let text = text.as_bytes();
+
+    let parse = time(options, || solution.parse(&mut &text[..]))?;
+    let input = solution.parse(&mut &text[..])?;
+
+    let mut result = DayBench {
+        parse: Some(parse),
+        ..Default::default()
+    };
+    for part in parts {
+        let params = Params::resolve(solution.params(), *part, &BTreeMap::new())?;
+        let stats = time(options, || solution.solve(input.as_ref(), *part, &params))?;
+        match part {
+            Part::One => result.part1 = Some(stats),
+            Part::Two => result.part2 = Some(stats),
+        }
+    }
+    Ok(result)
+}
+
+/// Saved benchmark results for each day, keyed by zero-padded day like the answers file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    days: BTreeMap<String, DayBench>,
+}
+
+impl Baseline {
+    /// Loads the baseline file, treating a missing file as having no baseline yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).with_context(|| format!("Could not parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: u8) -> Option<&DayBench> {
+        self.days.get(&format!("{:02}", day))
+    }
+
+    /// Stores the measured parts, keeping any saved measurements this run didn't repeat.
+    pub fn record(&mut self, day: u8, bench: &DayBench) {
+        let saved = self.days.entry(format!("{:02}", day)).or_default();
+        saved.parse = bench.parse.or(saved.parse);
+        saved.part1 = bench.part1.or(saved.part1);
+        saved.part2 = bench.part2.or(saved.part2);
+    }
+}
+
+/// Prints a day's timings, each followed by how its median compares with the baseline.
+pub fn report(day: u8, bench: &DayBench, baseline: Option<&DayBench>) {
+    start_day(&day.to_string());
+    let base_entries = baseline.map(DayBench::entries);
+    for (i, (name, stats)) in bench.entries().into_iter().enumerate() {
+        let Some(stats) = stats else { continue };
+        println!("{}: {}", name, stats);
+        if let Some(base) = base_entries.and_then(|entries| entries[i].1) {
+            let change = stats.median as f64 / base.median.max(1) as f64 - 1.0;
+            println!(
+                "  vs baseline median {:?}: {:+.1}%",
+                Duration::from_nanos(base.median),
+                change * 100.0
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median: u64) -> Stats {
+        Stats::from_samples(&[Duration::from_nanos(median)])
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let samples: Vec<_> = (1..=20).map(Duration::from_nanos).collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.runs, 20);
+        assert_eq!(stats.min, 1);
+        assert_eq!(stats.median, 10); // 10.5 truncated
+        assert_eq!(stats.mean, 10);
+        assert_eq!(stats.stddev, 5); // sqrt(33.25)
+        assert_eq!(stats.p95, 19);
+    }
+
+    #[test]
+    fn flags_regressions_over_threshold() {
+        let baseline = DayBench {
+            parse: Some(stats(100)),
+            part1: Some(stats(100)),
+            part2: None,
+        };
+        let current = DayBench {
+            parse: Some(stats(105)),
+            part1: Some(stats(150)),
+            part2: Some(stats(1000)),
+        };
+        let regressions = current.regressions(&baseline, 0.1);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].0, "Part 1");
+        assert!((regressions[0].1 - 0.5).abs() < 1e-9);
+    }
+}
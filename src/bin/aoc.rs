@@ -0,0 +1,496 @@
+use adv_code_2024::*;
+use anyhow::*;
+use clap::{Parser, Subcommand};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Show the days' debug output, or with -vv every step they trace
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Which days log what, e.g. `15=trace,21=debug`. Defaults to $AOC_LOG.
+    #[arg(long, global = true, value_name = "FILTER")]
+    log: Option<LogFilter>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle (or every day's) against its input file
+    Run {
+        /// Day number, or `all`
+        day: DaySelection,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the input from this file instead of input/NN.txt, or `-` for stdin. `{day}` is
+        /// replaced with the two-digit day. Defaults to $AOC_INPUT if that's set.
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// Solve one of the day's examples instead, by its name in input/examples/NN/
+        #[arg(long, conflicts_with = "input")]
+        example: Option<String>,
+
+        /// Change one of the day's puzzle constants, e.g. `--param blinks=40`. Repeatable.
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_override)]
+        params: Vec<(String, String)>,
+
+        /// Record the simulation to this .gif, or to numbered .png or .ppm frames beside it. Needs
+        /// `--part` and a single day, so nothing else records over it
+        #[arg(long, value_name = "PATH", group = "recording", requires = "part")]
+        record: Option<PathBuf>,
+
+        /// Step through the simulation in the terminal once it's run
+        #[arg(long, group = "recording")]
+        play: bool,
+
+        /// Keep one recorded step in this many
+        #[arg(long, default_value_t = 1, requires = "recording")]
+        every: usize,
+
+        /// Only record this part of the grid
+        #[arg(long, value_name = "X,Y,WIDTH,HEIGHT", requires = "recording")]
+        crop: Option<Crop>,
+
+        /// Pixels per cell in the recording
+        #[arg(long, default_value_t = 4, requires = "record")]
+        scale: usize,
+
+        /// Hundredths of a second each frame of a GIF shows for
+        #[arg(long, default_value_t = 5, requires = "record")]
+        delay: u16,
+    },
+    /// List the puzzle constants a day lets you change with `run --param`
+    Params { day: u8 },
+    /// Time parsing and each part over many runs, and compare with the saved baseline
+    Bench {
+        /// Day number, or `all`
+        day: DaySelection,
+
+        /// Only time this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Timed runs per measurement
+        #[arg(long, default_value_t = 20)]
+        runs: usize,
+
+        /// Untimed runs before timing starts
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Percentage slowdown of the median that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Overwrite the baseline with these timings (days without one are always saved)
+        #[arg(long)]
+        save: bool,
+    },
+    /// Download a day's puzzle input to input/NN.txt, unless it's already there
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// List the examples in a puzzle page saved from the browser, or save one of them
+    Extract {
+        /// The saved page, e.g. `Day 7 - Advent of Code 2024.html`
+        page: PathBuf,
+
+        /// Save this example (numbered as listed) to input/examples/NN/
+        #[arg(long)]
+        pick: Option<usize>,
+
+        /// Print the picked example as a Rust `TEST` constant instead of saving it
+        #[arg(long, requires = "pick")]
+        rust: bool,
+
+        /// The day the page is for, if its heading doesn't say
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Send an answer to the puzzle site, unless it's already known to be right or wrong
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        answer: Answer,
+    },
+    /// Save an answer the puzzle site accepted, so later runs can check against it
+    Record {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        answer: Answer,
+    },
+    /// Write a random puzzle input for a day, to see how it scales or find inputs it gets wrong
+    Generate {
+        day: u8,
+
+        /// How big to make it: a grid's side, or how many lines, depending on the day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+
+        /// The same seed gives the same input. Picked at random and printed if not given.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Write the input here instead of to stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Check a day's solution against its slow reference on many small generated inputs
+    Diff {
+        day: u8,
+
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// How many inputs to try
+        #[arg(long, default_value_t = 100)]
+        cases: usize,
+
+        /// The largest input to try, as for `generate`
+        #[arg(long, default_value_t = 6, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+
+        /// The first input's seed. Picked at random and printed if not given.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Change one of the day's puzzle constants, to keep the reference quick. Repeatable.
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_override)]
+        params: Vec<(String, String)>,
+    },
+}
+
+#[derive(Clone)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "all" => Ok(Self::All),
+            _ => Ok(Self::Day(
+                s.parse().context("Expected a day number or `all`")?,
+            )),
+        }
+    }
+}
+
+/// The seed given, or one from the clock, printed so the run can be repeated.
+fn seed_or_random(seed: Option<u64>) -> u64 {
+    seed.unwrap_or_else(|| {
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH);
+        let seed = now.unwrap_or_default().as_nanos() as u64;
+        eprintln!("Using --seed {}", seed);
+        seed
+    })
+}
+
+fn to_part(part: u8) -> Part {
+    match part {
+        1 => Part::One,
+        _ => Part::Two,
+    }
+}
+
+fn parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![to_part(part)],
+        None => vec![Part::One, Part::Two],
+    }
+}
+
+fn selected_days(day: DaySelection) -> Result<Vec<&'static dyn AnySolution>> {
+    match day {
+        DaySelection::Day(day) => Ok(vec![
+            days::get(day).ok_or_else(|| anyhow!("Day {} is not solved", day))?
+        ]),
+        DaySelection::All => Ok(days::ALL.to_vec()),
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let filter = match cli.log {
+        Some(filter) => filter,
+        None => log_filter_from_env()?,
+    };
+    set_log_filter(filter.at_least(match cli.verbose {
+        0 => Level::Off,
+        1 => Level::Debug,
+        _ => Level::Trace,
+    }));
+    let mut known = KnownAnswers::load(ANSWERS_FILE)?;
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            example,
+            params,
+            record,
+            play,
+            every,
+            crop,
+            scale,
+            delay,
+        } => {
+            ensure!(
+                record.is_none() || !matches!(day, DaySelection::All),
+                "--record needs a single day, or every day would record over the same file"
+            );
+            let output = match (record, play) {
+                (Some(path), _) => Some(Output::File(path)),
+                (None, true) => Some(Output::Player),
+                (None, false) => None,
+            };
+            set_recording(output.map(|output| RecordOptions {
+                output,
+                every,
+                crop,
+                scale,
+                delay,
+            }));
+            let parts = parts(part);
+            let overrides = BTreeMap::from_iter(params);
+            let source = |solution: &dyn AnySolution| {
+                InputSource::resolve(solution.day(), input.as_deref(), example.as_deref())
+            };
+
+            match day {
+                DaySelection::Day(day) => {
+                    let solution =
+                        days::get(day).ok_or_else(|| anyhow!("Day {} is not solved", day))?;
+                    runner::run(solution, &source(solution), &overrides, &parts, &known)
+                }
+                DaySelection::All => {
+                    let mut failed = Vec::new();
+                    for solution in days::ALL {
+                        if let Err(error) =
+                            runner::run(*solution, &source(*solution), &overrides, &parts, &known)
+                        {
+                            println!("Error: {:#}", error);
+                            failed.push(solution.day());
+                        }
+                        println!();
+                    }
+                    ensure!(failed.is_empty(), "Days failed: {:?}", failed);
+                    Ok(())
+                }
+            }
+        }
+        Command::Bench {
+            day,
+            part,
+            runs,
+            warmup,
+            threshold,
+            save,
+        } => {
+            let parts = parts(part);
+            let options = bench::BenchOptions { warmup, runs };
+            let mut baseline = bench::Baseline::load(bench::BASELINE_FILE)?;
+            let mut regressed = Vec::new();
+            let mut failed = Vec::new();
+
+            for solution in selected_days(day)? {
+                let day = solution.day();
+                let timings = match bench::bench(solution, &parts, options) {
+                    Result::Ok(timings) => timings,
+                    Err(error) => {
+                        println!("Day {:02}: error: {:#}\n", day, error);
+                        failed.push(day);
+                        continue;
+                    }
+                };
+                bench::report(day, &timings, baseline.get(day));
+
+                match baseline.get(day) {
+                    Some(saved) => {
+                        for (name, change) in timings.regressions(saved, threshold / 100.0) {
+                            println!("REGRESSION: {} is {:.1}% slower", name, change * 100.0);
+                            regressed.push(day);
+                        }
+                        if save {
+                            baseline.record(day, &timings);
+                        }
+                    }
+                    None => baseline.record(day, &timings),
+                }
+                println!();
+            }
+
+            baseline.save(bench::BASELINE_FILE)?;
+            regressed.dedup();
+            ensure!(failed.is_empty(), "Days failed: {:?}", failed);
+            ensure!(regressed.is_empty(), "Days regressed: {:?}", regressed);
+            Ok(())
+        }
+        Command::Params { day } => {
+            let solution = days::get(day).ok_or_else(|| anyhow!("Day {} is not solved", day))?;
+            if solution.params().is_empty() {
+                println!("Day {} has no parameters", day);
+            }
+            for param in solution.params() {
+                let default = match param.part2_default {
+                    Some(part2) => format!("{} (part 2: {})", param.default, part2),
+                    None => param.default.to_string(),
+                };
+                println!("{} = {}\n    {}", param.name, default, param.description);
+            }
+            Ok(())
+        }
+        Command::Fetch { day } => {
+            let config = client::Config::load(client::CONFIG_FILE)?;
+            let path = runner::input_path(day);
+            match client::fetch_input(&config, day, Path::new("input"))? {
+                client::Fetched::Cached => println!("{} is already downloaded", path),
+                client::Fetched::Downloaded => println!("Saved day {}'s input to {}", day, path),
+            }
+            Ok(())
+        }
+        Command::Extract {
+            page,
+            pick,
+            rust,
+            day,
+        } => {
+            let page = puzzle_page::PuzzlePage::load(&page)?;
+            ensure!(!page.blocks.is_empty(), "The page has no examples");
+            let Some(pick) = pick else {
+                for (number, block) in page.blocks.iter().enumerate() {
+                    let answers = [("part1", &block.part1), ("part2", &block.part2)]
+                        .into_iter()
+                        .filter_map(|(key, answer)| Some(format!("{} = {}", key, answer.as_ref()?)))
+                        .collect::<Vec<_>>();
+                    let lines = match block.text.lines().count() {
+                        1 => "1 line".to_string(),
+                        count => format!("{} lines", count),
+                    };
+                    println!(
+                        "{}: in part {}, {}, {}",
+                        number + 1,
+                        block.part,
+                        lines,
+                        match answers.is_empty() {
+                            true => "no answer found".to_string(),
+                            false => answers.join(", "),
+                        }
+                    );
+                    for line in block.text.lines().take(3) {
+                        println!("    {}", line);
+                    }
+                }
+                return Ok(());
+            };
+
+            let block = pick
+                .checked_sub(1)
+                .and_then(|index| page.blocks.get(index))
+                .with_context(|| format!("The page has examples 1 to {}", page.blocks.len()))?;
+            if rust {
+                println!("{}", block.rust_const());
+                return Ok(());
+            }
+            let day = day
+                .or(page.day)
+                .context("The page doesn't say which day it is; pass --day")?;
+            let path = puzzle_page::save_example(day, block)?;
+            println!("Saved {}; check the answers in its header", path.display());
+            Ok(())
+        }
+        Command::Submit { day, part, answer } => {
+            let config = client::Config::load(client::CONFIG_FILE)?;
+            let mut guesses = Guesses::load(GUESSES_FILE)?;
+            let reply = client::submit_answer(
+                &config,
+                day,
+                to_part(part),
+                &answer,
+                Path::new("input"),
+                &mut known,
+                &mut guesses,
+            )?;
+            println!("{}", reply);
+            match reply {
+                client::Reply::Correct => {
+                    known.save(ANSWERS_FILE)?;
+                    println!("Recorded day {:02} part {}: {}", day, part, answer);
+                }
+                client::Reply::Wrong(_) => guesses.save(GUESSES_FILE)?,
+                client::Reply::RateLimited { .. } => {}
+            }
+            Ok(())
+        }
+        Command::Record { day, part, answer } => {
+            known.record(day, to_part(part), &answer);
+            known.save(ANSWERS_FILE)?;
+            println!("Recorded day {:02} part {}: {}", day, part, answer);
+            Ok(())
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let solution = days::get(day).ok_or_else(|| anyhow!("Day {} is not solved", day))?;
+            let seed = seed_or_random(seed);
+            let input = solution.generate(size as usize, &mut Rng::new(seed))?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, input)
+                        .with_context(|| format!("Could not write {}", path.display()))?;
+                    println!("Wrote day {}'s input to {}", day, path.display());
+                }
+                None => print!("{}", input),
+            }
+            Ok(())
+        }
+        Command::Diff {
+            day,
+            part,
+            cases,
+            size,
+            seed,
+            params,
+        } => {
+            let solution = days::get(day).ok_or_else(|| anyhow!("Day {} is not solved", day))?;
+            let options = differential::DiffOptions {
+                cases,
+                size: size as usize,
+                overrides: BTreeMap::from_iter(params),
+                ..differential::DiffOptions::new(to_part(part), seed_or_random(seed))
+            };
+            differential::check_reference(solution, &options)?;
+            println!(
+                "Day {} part {} agrees with its reference on {} inputs",
+                day, part, cases
+            );
+            Ok(())
+        }
+    }
+}
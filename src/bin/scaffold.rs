@@ -0,0 +1,49 @@
+use adv_code_2024::*;
+use anyhow::*;
+use clap::Parser;
+use std::path::Path;
+use std::process::Command;
+
+/// Start a new day from the template, with a file for the puzzle's example
+#[derive(Parser)]
+struct Args {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Open the new files in $VISUAL or $EDITOR
+    #[arg(long)]
+    edit: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    // Paths are relative to the repository, wherever this is run from
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let created = scaffold::scaffold(root, args.day)?;
+    println!("Created {}", created.source.display());
+    println!("Created {}", created.example.display());
+    println!(
+        "Save the puzzle input to {} and paste the example into {}",
+        runner::input_path(args.day),
+        created.example.display()
+    );
+
+    if args.edit {
+        let editor = std::env::var("VISUAL")
+            .or_else(|_| std::env::var("EDITOR"))
+            .context("Set $VISUAL or $EDITOR to open the new files")?;
+        // Editors are often given with arguments, like `code --wait`
+        let mut words = editor.split_whitespace();
+        let program = words.next().context("$EDITOR is empty")?;
+        let status = Command::new(program)
+            .args(words)
+            .arg(root.join(&created.source))
+            .arg(root.join(&created.example))
+            .status()
+            .with_context(|| format!("Could not run {}", editor))?;
+        ensure!(status.success(), "{} exited with {}", editor, status);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use adv_code_2024::*;
+use anyhow::*;
+
+/// Re-solves every day with a known answer and reports whether it still matches.
+fn main() -> Result<()> {
+    let known = KnownAnswers::load(ANSWERS_FILE)?;
+    let mut failed = Vec::new();
+
+    for solution in days::ALL {
+        match runner::verify(*solution, &known) {
+            Result::Ok(verdicts) => {
+                for (part, verdict) in verdicts {
+                    println!("Day {:02} part {}: {}", solution.day(), part, verdict);
+                    if matches!(verdict, Verdict::Fail { .. }) {
+                        failed.push(solution.day());
+                    }
+                }
+            }
+            Err(error) => {
+                println!("Day {:02}: error: {:#}", solution.day(), error);
+                failed.push(solution.day());
+            }
+        }
+    }
+
+    failed.dedup();
+    ensure!(failed.is_empty(), "Days failed: {:?}", failed);
+    Ok(())
+}
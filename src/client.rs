@@ -0,0 +1,566 @@
+//! Talking to the puzzle site. Every request waits its turn behind the last one (even one made
+//! by an earlier run) and says who's asking in its User-Agent, as the site asks of tools.
+
+use crate::*;
+use anyhow::*;
+use regex::Regex;
+use serde::Deserialize;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Optional settings, kept out of git as the session token is as good as a password:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."   # the site's `session` cookie
+/// base_url = "http://localhost:8000" # defaults to https://adventofcode.com
+/// min_interval = 5                   # seconds between requests
+/// contact = "you@example.com"        # added to the User-Agent
+/// ```
+pub const CONFIG_FILE: &str = "aoc.toml";
+/// Overrides the config file's `session`.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Overrides the config file's `base_url`.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_MIN_INTERVAL: f64 = 5.0;
+const YEAR: u16 = 2024;
+const USER_AGENT: &str = "github.com/samollari/advent-of-code-2024";
+/// Where the time of the last request is kept, inside the input directory.
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub min_interval: Option<f64>,
+    pub contact: Option<String>,
+}
+
+impl Config {
+    /// Reads the config file if there is one, then applies the environment variables.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut config: Self = match path.exists() {
+            true => toml::from_str(&fs::read_to_string(path)?)
+                .with_context(|| format!("Could not parse {}", path.display()))?,
+            false => Self::default(),
+        };
+        if let Result::Ok(session) = std::env::var(SESSION_ENV) {
+            config.session = Some(session);
+        }
+        if let Result::Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request_file: PathBuf,
+}
+
+impl Client {
+    /// A client that keeps its request times in `state_dir`.
+    pub fn new(config: &Config, state_dir: &Path) -> Result<Self> {
+        let session = config
+            .session
+            .as_deref()
+            .map(str::trim)
+            .filter(|session| !session.is_empty())
+            .with_context(|| {
+                format!(
+                    "No session token: log in to the site, then put the value of its `session` \
+                     cookie in ${} or as `session` in {}",
+                    SESSION_ENV, CONFIG_FILE
+                )
+            })?;
+        let user_agent = match &config.contact {
+            Some(contact) => format!("{} by {}", USER_AGENT, contact),
+            None => USER_AGENT.to_string(),
+        };
+        let agent = ureq::Agent::config_builder()
+            .user_agent(user_agent)
+            .http_status_as_error(false)
+            // The site redirects to its login page when the session isn't accepted
+            .max_redirects(0)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Ok(Self {
+            agent,
+            base_url: config
+                .base_url
+                .as_deref()
+                .unwrap_or(DEFAULT_BASE_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            session: session.to_string(),
+            min_interval: Duration::from_secs_f64(
+                config.min_interval.unwrap_or(DEFAULT_MIN_INTERVAL),
+            ),
+            last_request_file: state_dir.join(LAST_REQUEST_FILE),
+        })
+    }
+
+    pub fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, then notes this one.
+    fn wait_turn(&self) -> Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        let last = fs::read_to_string(&self.last_request_file)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(wait) = last.and_then(|last| (last + self.min_interval).checked_sub(now)) {
+            std::thread::sleep(wait);
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        fs::write(&self.last_request_file, now.as_millis().to_string())
+            .with_context(|| format!("Could not write {}", self.last_request_file.display()))
+    }
+
+    /// Requests `url` with the session cookie, returning the status and body.
+    fn get(&self, url: &str) -> Result<(u16, String)> {
+        self.wait_turn()?;
+        let response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call();
+        Self::read(url, response)
+    }
+
+    /// Posts `form` to `url` with the session cookie, returning the status and body.
+    fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<(u16, String)> {
+        self.wait_turn()?;
+        let response = self
+            .agent
+            .post(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form(form.iter().copied());
+        Self::read(url, response)
+    }
+
+    fn read(
+        url: &str,
+        response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    ) -> Result<(u16, String)> {
+        let mut response = response.with_context(|| format!("Could not reach {}", url))?;
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+
+        if status == 400 || status == 401 || status == 403 || (300..400).contains(&status) {
+            bail!(
+                "The site didn't accept the session token ({} from {}); it has probably expired, \
+                 so log in again and copy the new `session` cookie",
+                status,
+                url
+            );
+        }
+        Ok((status, body))
+    }
+
+    /// Downloads the day's puzzle input.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        let (status, body) = self.get(&url)?;
+        match status {
+            200 => Ok(body),
+            404 => bail!("Day {} isn't unlocked yet ({} was not found)", day, url),
+            _ => bail!(
+                "{} returned {}: {}",
+                url,
+                status,
+                body.lines().next().unwrap_or("")
+            ),
+        }
+    }
+
+    /// Sends an answer for one part of the day and reads the site's verdict.
+    pub fn answer(&self, day: u8, part: Part, answer: &Answer) -> Result<Reply> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+        let (status, body) =
+            self.post(&url, &[("level", level), ("answer", &answer.to_string())])?;
+        ensure!(status == 200, "{} returned {}", url, status);
+        Reply::classify(&body)
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Reply {
+    Correct,
+    Wrong(Option<Hint>),
+    /// Another answer was sent too recently, so this one wasn't checked.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+}
+
+impl Reply {
+    /// Reads the verdict from the text of the page the site sends back.
+    pub fn classify(page: &str) -> Result<Self> {
+        if page.contains("That's the right answer") {
+            return Ok(Reply::Correct);
+        }
+        if page.contains("That's not the right answer") {
+            let hint = if page.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            return Ok(Reply::Wrong(hint));
+        }
+        if page.contains("You gave an answer too recently") {
+            let left = Regex::new(r"(?i)you have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let wait = left.captures(page).map(|captures| {
+                let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                Duration::from_secs(minutes * 60 + captures[2].parse::<u64>().unwrap())
+            });
+            return Ok(Reply::RateLimited { wait });
+        }
+        if page.contains("You don't seem to be solving the right level") {
+            bail!("That part isn't open: it's already solved, or its first part isn't yet");
+        }
+        bail!("Couldn't make sense of the site's reply:\n{}", page.trim())
+    }
+}
+
+impl Display for Reply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reply::Correct => write!(f, "That's the right answer!"),
+            Reply::Wrong(Some(hint)) => write!(f, "That's not the right answer; it's {}", hint),
+            Reply::Wrong(None) => write!(f, "That's not the right answer"),
+            Reply::RateLimited { wait: Some(wait) } => write!(
+                f,
+                "An answer was sent too recently; wait {}s",
+                wait.as_secs()
+            ),
+            Reply::RateLimited { wait: None } => {
+                write!(f, "An answer was sent too recently; wait a while")
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there, so nothing was requested.
+    Cached,
+    Downloaded,
+}
+
+/// Makes sure `input_dir` has the day's input, downloading it only if it isn't there already.
+/// An empty file counts as missing.
+pub fn fetch_input(config: &Config, day: u8, input_dir: &Path) -> Result<Fetched> {
+    ensure!(
+        (1..=25).contains(&day),
+        "Advent of Code only has days 1 to 25"
+    );
+    let path = input_dir.join(format!("{:02}.txt", day));
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let client = Client::new(config, input_dir)?;
+    let input = client.input(day)?;
+    ensure!(
+        !input.trim().is_empty(),
+        "The site sent an empty input for day {}",
+        day
+    );
+
+    // Write it whole or not at all, so a failed download can't leave a partial input behind
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, &path)?;
+    Ok(Fetched::Downloaded)
+}
+
+/// Submits an answer, unless the known answers or earlier guesses already show whether it's
+/// right, and notes what the site says about it in `known` or `guesses`.
+pub fn submit_answer(
+    config: &Config,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    input_dir: &Path,
+    known: &mut KnownAnswers,
+    guesses: &mut Guesses,
+) -> Result<Reply> {
+    ensure!(
+        !matches!(answer, Answer::Grid(_)),
+        "The answer is a picture; submit the letters it shows instead"
+    );
+    match known.check(day, part, answer) {
+        Verdict::Pass => bail!("{} is already recorded as the answer", answer),
+        Verdict::Fail { expected } => bail!(
+            "Day {} part {} is already solved, with {}",
+            day,
+            part,
+            expected
+        ),
+        Verdict::Unknown => {}
+    }
+    if let Some(reason) = guesses.rule_out(day, part, answer) {
+        bail!("Not submitting: {}", reason);
+    }
+
+    let reply = Client::new(config, input_dir)?.answer(day, part, answer)?;
+    match reply {
+        Reply::Correct => known.record(day, part, answer),
+        Reply::Wrong(hint) => guesses.record(day, part, answer, hint),
+        Reply::RateLimited { .. } => {}
+    }
+    Ok(reply)
+}
+
+/// A stand-in for the puzzle site that replies to each request in turn and reports what it was
+/// sent, so tests don't touch the network.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{channel, Receiver};
+
+    /// A request the stub received: its request line, headers and body.
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find_map(|header| {
+                let (key, value) = header.split_once(": ")?;
+                key.eq_ignore_ascii_case(name).then_some(value)
+            })
+        }
+    }
+
+    /// Serves one reply per `(status, body)` and returns the base URL to point a client at.
+    pub fn serve(replies: Vec<(u16, &'static str)>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = channel();
+
+        std::thread::spawn(move || {
+            for (status, body) in replies {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(header.trim().to_string());
+                }
+                let mut request = Request {
+                    line: line.trim().to_string(),
+                    headers,
+                    body: String::new(),
+                };
+                let length = request
+                    .header("Content-Length")
+                    .map_or(0, |length| length.parse().unwrap());
+                let mut received = vec![0; length];
+                reader.read_exact(&mut received).unwrap();
+                request.body = String::from_utf8(received).unwrap();
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn config(base_url: &str) -> Config {
+        Config {
+            session: Some("abc123".to_string()),
+            base_url: Some(base_url.to_string()),
+            min_interval: Some(0.2),
+            contact: Some("me@example.com".to_string()),
+        }
+    }
+
+    #[test]
+    fn downloads_once_and_waits_between_requests() {
+        let (url, requests) = stub::serve(vec![(200, "1 2\n3 4\n"), (200, "5 6\n")]);
+        let dir = temp_dir("fetch");
+        let config = config(&url);
+
+        let start = Instant::now();
+        assert_eq!(fetch_input(&config, 1, &dir).unwrap(), Fetched::Downloaded);
+        assert_eq!(fetch_input(&config, 1, &dir).unwrap(), Fetched::Cached);
+        assert_eq!(fetch_input(&config, 2, &dir).unwrap(), Fetched::Downloaded);
+        assert!(start.elapsed() >= Duration::from_secs_f64(0.2));
+
+        assert_eq!(
+            fs::read_to_string(dir.join("01.txt")).unwrap(),
+            "1 2\n3 4\n"
+        );
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2024/day/1/input HTTP/1.1");
+        assert_eq!(request.header("Cookie"), Some("session=abc123"));
+        assert_eq!(
+            request.header("User-Agent"),
+            Some("github.com/samollari/advent-of-code-2024 by me@example.com")
+        );
+        assert_eq!(
+            requests.recv().unwrap().line,
+            "GET /2024/day/2/input HTTP/1.1"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fails_cleanly_without_a_working_token() {
+        let (url, _requests) = stub::serve(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+        ]);
+        let dir = temp_dir("fetch-errors");
+
+        let missing = Config {
+            session: None,
+            ..config(&url)
+        };
+        let error = fetch_input(&missing, 1, &dir).unwrap_err().to_string();
+        assert!(error.starts_with("No session token"));
+
+        let error = fetch_input(&config(&url), 1, &dir).unwrap_err().to_string();
+        assert!(error.contains("probably expired"));
+        let error = fetch_input(&config(&url), 25, &dir)
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("Day 25 isn't unlocked yet"));
+
+        assert!(!dir.join("01.txt").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn submits_each_answer_once() {
+        let (url, requests) = stub::serve(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>You gave an answer too recently. You have 4m 2s left to wait.</p></article>",
+            ),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let dir = temp_dir("submit");
+        let config = config(&url);
+        let mut known = KnownAnswers::default();
+        let mut guesses = Guesses::default();
+        let mut submit = |answer: usize| {
+            submit_answer(
+                &config,
+                3,
+                Part::One,
+                &answer.into(),
+                &dir,
+                &mut known,
+                &mut guesses,
+            )
+        };
+
+        assert_eq!(submit(100).unwrap(), Reply::Wrong(Some(Hint::TooHigh)));
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2024/day/3/answer HTTP/1.1");
+        assert_eq!(request.body, "level=1&answer=100");
+
+        // Ruled out without asking the site
+        assert!(submit(100).is_err());
+        assert!(submit(120).is_err());
+
+        assert_eq!(
+            submit(40).unwrap(),
+            Reply::RateLimited {
+                wait: Some(Duration::from_secs(242))
+            }
+        );
+        assert_eq!(submit(40).unwrap(), Reply::Correct);
+        assert!(submit(40).is_err());
+        assert_eq!(known.get(3, Part::One).unwrap(), 40usize);
+        assert!(guesses.rule_out(3, Part::One, &100usize.into()).is_some());
+        assert_eq!(requests.iter().count(), 2);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn classifies_replies() {
+        let classify = |text| Reply::classify(text).unwrap();
+        assert_eq!(
+            classify("That's not the right answer; your answer is too low."),
+            Reply::Wrong(Some(Hint::TooLow))
+        );
+        assert_eq!(
+            classify("That's not the right answer.  If you're stuck, ..."),
+            Reply::Wrong(None)
+        );
+        assert_eq!(
+            classify("You gave an answer too recently; you have 35s left to wait."),
+            Reply::RateLimited {
+                wait: Some(Duration::from_secs(35))
+            }
+        );
+        assert!(Reply::classify("You don't seem to be solving the right level.").is_err());
+        assert!(Reply::classify("<html>Gateway timeout</html>").is_err());
+    }
+}
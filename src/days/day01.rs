@@ -0,0 +1,81 @@
+use crate::*;
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let pairs = Span::new(&input)
+            .lines()
+            .map(|line| {
+                let (left, right) = line.split_once("   ")?;
+                Ok((left.parse()?, right.parse()?))
+            })
+            .collect::<Result<Vec<(usize, usize)>>>()?;
+        Ok(pairs.into_iter().unzip())
+    }
+
+    fn part1((left_list, right_list): &Self::Input, _params: &Params) -> Result<Answer> {
+        let mut left_list = left_list.clone();
+        let mut right_list = right_list.clone();
+        left_list.sort();
+        right_list.sort();
+        let answer: usize = left_list
+            .iter()
+            .zip(right_list)
+            .map(|(left, right)| left.abs_diff(right))
+            .sum();
+        Ok(answer.into())
+    }
+
+    fn part2((left_list, right_list): &Self::Input, _params: &Params) -> Result<Answer> {
+        let mut similarity_score = 0;
+        let mut right_counts: HashMap<usize, usize> = HashMap::new();
+
+        for element in right_list {
+            right_counts.insert(*element, *right_counts.get(element).unwrap_or(&0) + 1);
+        }
+
+        for element in left_list {
+            similarity_score += right_counts.get(element).unwrap_or(&0) * element;
+        }
+
+        Ok(similarity_score.into())
+    }
+
+    /// `size` pairs of location IDs, with about half the right list repeating the left.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let left: Vec<i64> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+        let mut lists = String::new();
+        for id in &left {
+            let right = match rng.chance(0.5) {
+                true => *rng.pick(&left),
+                false => rng.range(10000..=99999),
+            };
+            lists += &format!("{}   {}\n", id, right);
+        }
+        Ok(lists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day01, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day01, &[Part::Two])
+    }
+}
@@ -0,0 +1,135 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+
+pub struct Day02;
+
+#[derive(PartialEq, Debug)]
+enum ChangeType {
+    Increasing,
+    Decreasing,
+}
+
+/// Widened, so levels at either end of `i32` can't overflow.
+fn get_diff_between_elements(parts: &[i32], a: usize, b: usize) -> Option<i64> {
+    Some(*parts.get(b)? as i64 - *parts.get(a)? as i64)
+}
+
+fn get_change_type(diff: i64) -> Option<ChangeType> {
+    if diff < 0 {
+        Some(ChangeType::Decreasing)
+    } else if diff > 0 {
+        Some(ChangeType::Increasing)
+    } else {
+        None
+    }
+}
+
+fn report_is_safe(levels: &[i32]) -> bool {
+    let diffs = (1..levels.len()).filter_map(|i| get_diff_between_elements(levels, i - 1, i));
+    // A report of one level never changes in the wrong way
+    let Some(first_diff) = diffs.clone().next() else {
+        return true;
+    };
+    let change_type = match get_change_type(first_diff) {
+        Some(x) => x,
+        None => return false,
+    };
+
+    for diff_to_next in diffs {
+        match get_change_type(diff_to_next) {
+            Some(this_change) => {
+                if this_change != change_type {
+                    return false;
+                }
+            }
+            None => {
+                return false;
+            }
+        }
+
+        let abs_diff = diff_to_next.unsigned_abs();
+        if !(1..=3).contains(&abs_diff) {
+            return false;
+        }
+    }
+    true
+}
+
+fn dropped_level_variants(levels: Vec<i32>) -> Vec<Vec<i32>> {
+    let mut variants = Vec::new();
+    for i in 0..levels.len() {
+        let pre = &levels[..i];
+        let post = &levels[i + 1..];
+        variants.push(pre.iter().chain(post).cloned().collect_vec());
+    }
+    variants
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        Ok(parse_all(&input, lines(list(" ", int)))?)
+    }
+
+    fn part1(reports: &Self::Input, _params: &Params) -> Result<Answer> {
+        let lines = reports.iter().filter(|levels| report_is_safe(levels));
+
+        Ok(lines.count().into())
+    }
+
+    fn part2(reports: &Self::Input, _params: &Params) -> Result<Answer> {
+        let lines = reports.iter().filter(|levels| {
+            let is_safe_natively = report_is_safe(levels);
+            if is_safe_natively {
+                return true;
+            }
+
+            let variants = dropped_level_variants(levels.to_vec());
+
+            variants.into_iter().any(|report| report_is_safe(&report))
+        });
+
+        Ok(lines.count().into())
+    }
+
+    /// `size` reports that start out safe, some with one level knocked out of line.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let mut reports = String::new();
+        for _ in 0..size {
+            let direction = *rng.pick(&[-1, 1]);
+            let mut level = rng.range(30..=70);
+            let mut levels = Vec::new();
+            for _ in 0..rng.range(5..=8) {
+                levels.push(level);
+                level += direction * rng.range(1..=3);
+            }
+            if rng.chance(0.4) {
+                let unsafe_level = rng.below(levels.len());
+                levels[unsafe_level] = rng.range(1..=99);
+            }
+            reports += &format!("{}\n", levels.iter().join(" "));
+        }
+        Ok(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day02, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day02, &[Part::Two])
+    }
+}
@@ -0,0 +1,145 @@
+use crate::*;
+use anyhow::*;
+use regex::Regex;
+use std::io::BufRead;
+
+pub struct Day03;
+
+pub struct MulCall {
+    left: u32,
+    right: u32,
+}
+
+impl MulCall {
+    fn parse(l_span: Span, r_span: Span) -> Result<Self, ParseError> {
+        Result::Ok(Self {
+            left: int(l_span)?,
+            right: int(r_span)?,
+        })
+    }
+
+    /// Widened, as products of long numbers don't fit in 32 bits.
+    fn call(&self) -> u64 {
+        self.left as u64 * self.right as u64
+    }
+}
+
+pub enum Operation {
+    Do,
+    Dont,
+    Mul(MulCall),
+}
+
+enum OperationResult {
+    Enabled(u64),
+    Disabled(u64),
+}
+
+impl From<OperationResult> for u64 {
+    fn from(value: OperationResult) -> Self {
+        match value {
+            OperationResult::Enabled(v) => v,
+            OperationResult::Disabled(v) => v,
+        }
+    }
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Operation>;
+
+    /// Picks the instructions out of the memory, skipping the corrupted parts between them.
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let op_re = Regex::new(r"do\(\)|don't\(\)|mul\((\d+),(\d+)\)").unwrap();
+        let operations = |span: Span| {
+            op_re
+                .captures_iter(&span)
+                .map(|c| match (c.get(1), c.get(2)) {
+                    (Some(l), Some(r)) => Result::Ok(Operation::Mul(MulCall::parse(
+                        span.slice(l.range()),
+                        span.slice(r.range()),
+                    )?)),
+                    _ if &c[0] == "do()" => Result::Ok(Operation::Do),
+                    _ => Result::Ok(Operation::Dont),
+                })
+                .collect()
+        };
+        Ok(parse_all(&input, operations)?)
+    }
+
+    fn part1(operations: &Self::Input, _params: &Params) -> Result<Answer> {
+        let v: u64 = operations
+            .iter()
+            .filter_map(|operation| match operation {
+                Operation::Mul(mul) => Some(mul.call()),
+                _ => None,
+            })
+            .sum();
+        Ok(v.into())
+    }
+
+    fn part2(operations: &Self::Input, _params: &Params) -> Result<Answer> {
+        let sum: u64 = operations
+            .iter()
+            .fold(
+                OperationResult::Enabled(0),
+                |result, operation| match operation {
+                    Operation::Do => OperationResult::Enabled(result.into()),
+                    Operation::Dont => OperationResult::Disabled(result.into()),
+                    Operation::Mul(mul_call) => match result {
+                        OperationResult::Enabled(v) => {
+                            OperationResult::Enabled(v + mul_call.call())
+                        }
+                        OperationResult::Disabled(_) => result,
+                    },
+                },
+            )
+            .into();
+
+        Ok(sum.into())
+    }
+
+    /// `size` pieces of memory, each an instruction, a near miss at one, or junk.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        const JUNK: &[char] = &[
+            '%', '$', '!', '@', '^', '&', '*', '(', ')', '[', ']', '{', '}', '<', '>', ',', ';',
+            ':', '\'', '?', '+', '-', ' ', 'm', 'u', 'l', 'w', 'h', 'y', 'd', 'o', 'n', 't',
+        ];
+        let mut memory = String::new();
+        for piece in 1..=size {
+            let (x, y) = (rng.range(1..=999), rng.range(1..=999));
+            memory += &match rng.below(10) {
+                0 => "do()".to_string(),
+                1 => "don't()".to_string(),
+                2 => format!("mul({},{}]", x, y),
+                3 => format!("mul ( {},{} )", x, y),
+                4..=6 => (0..rng.range(1..=8)).map(|_| *rng.pick(JUNK)).collect(),
+                _ => format!("mul({},{})", x, y),
+            };
+            if piece.is_multiple_of(50) {
+                memory.push('\n');
+            }
+        }
+        if !memory.ends_with('\n') {
+            memory.push('\n');
+        }
+        Ok(memory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day03, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day03, &[Part::Two])
+    }
+}
@@ -0,0 +1,112 @@
+use crate::*;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day04;
+
+/*
+part 1:
+....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX
+
+part 2:
+.M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........
+ */
+
+fn iter_direction(start: Coord, grid: &Grid<char>, direction: Direction) -> Option<String> {
+    let step = direction.to_delta();
+
+    // Any letter off the edge of the grid makes the whole word `None`
+    (0..4)
+        .map(|offset| grid.get(start + step * offset).copied())
+        .collect()
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Grid<char>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::from_reader(reader, |char| char)
+    }
+
+    fn part1(grid: &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(grid
+            .positions(|char| *char == 'X')
+            .map(|coord| {
+                Direction::ALL
+                    .iter()
+                    .filter_map(|direction| iter_direction(coord, grid, *direction))
+                    .filter(|str| str == "XMAS")
+                    .count()
+            })
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(grid: &Self::Input, _params: &Params) -> Result<Answer> {
+        let diagonal =
+            |coord: Coord, direction: Direction| grid.get(coord + direction.to_delta()).copied();
+
+        Ok(grid
+            .positions(|char| *char == 'A')
+            .filter(|coord| {
+                // \
+                let tl = diagonal(*coord, Direction::UpLeft);
+                let br = diagonal(*coord, Direction::DownRight);
+                if !matches!((tl, br), (Some('M'), Some('S')) | (Some('S'), Some('M'))) {
+                    return false;
+                }
+
+                // /
+                let bl = diagonal(*coord, Direction::DownLeft);
+                let tr = diagonal(*coord, Direction::UpRight);
+                matches!((bl, tr), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+            })
+            .count()
+            .into())
+    }
+
+    /// A `size` by `size` square of random X, M, A and S.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let letters = Grid::from_rows((0..size).map(|_| {
+            (0..size)
+                .map(|_| *rng.pick(&['X', 'M', 'A', 'S']))
+                .collect()
+        }))?;
+        Ok(format!("{}\n", letters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day04, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day04, &[Part::Two])
+    }
+}
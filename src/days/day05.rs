@@ -0,0 +1,141 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+pub struct Day05;
+
+type Rules = HashMap<usize, Vec<usize>>;
+
+fn disallowed_seen(page: &usize, seen: &HashSet<usize>, rules: &Rules) -> bool {
+    let this_page_rule = rules.get(page);
+    match this_page_rule {
+        Some(disallowed) => disallowed
+            .iter()
+            .any(|disallowed_val| seen.contains(disallowed_val)),
+        None => false,
+    }
+}
+
+fn correctly_ordered(update: &[usize], rules: &Rules) -> bool {
+    let mut seen: HashSet<usize> = HashSet::new();
+    for page in update {
+        if disallowed_seen(page, &seen, rules) {
+            return false;
+        }
+        seen.insert(*page);
+    }
+    // Correctly ordered!
+    true
+}
+
+fn middle_number(update: &[usize]) -> usize {
+    update[update.len() / 2]
+}
+
+fn x_before_y(x: &usize, y: &usize, rules: &Rules) -> bool {
+    match rules.get(x) {
+        Some(rule) => rule.contains(y),
+        None => false,
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = (Rules, Vec<Vec<usize>>);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let (rules, updates) = parse_all(
+            &input,
+            pair(
+                BLANK_LINE,
+                lines(pair("|", int, int)),
+                lines(list(",", int)),
+            ),
+        )?;
+
+        Ok((rules.into_iter().into_group_map(), updates))
+    }
+
+    fn part1((rules, updates): &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(updates
+            .iter()
+            .filter(|update| correctly_ordered(update, rules))
+            .map(|update| middle_number(update))
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2((rules, updates): &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(updates
+            .iter()
+            .filter(|update| !correctly_ordered(update, rules))
+            .map(|update| {
+                // Sort update according to rules
+                let mut update = update.to_owned();
+                update.sort_by(|a, b| {
+                    let a_before_b = x_before_y(a, b, rules);
+                    if a_before_b {
+                        return Ordering::Less;
+                    }
+
+                    let b_before_a = x_before_y(b, a, rules);
+                    if b_before_a {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    }
+                });
+                update
+            })
+            .map(|update| middle_number(&update))
+            .sum::<usize>()
+            .into())
+    }
+
+    /// 49 pages with a rule for every pair, as in the real input, and `size` updates of them,
+    /// about half already in order.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let mut pages: Vec<i64> = (10..=99).collect();
+        rng.shuffle(&mut pages);
+        pages.truncate(49);
+
+        let mut rules = pages
+            .iter()
+            .tuple_combinations()
+            .map(|(before, after)| format!("{}|{}", before, after))
+            .collect_vec();
+        rng.shuffle(&mut rules);
+
+        let mut updates = Vec::new();
+        for _ in 0..size {
+            let mut update = pages.clone();
+            rng.shuffle(&mut update);
+            update.truncate(5 + 2 * rng.below(10));
+            if rng.chance(0.5) {
+                update.sort_by_key(|page| pages.iter().position(|other| other == page));
+            }
+            updates.push(update.iter().join(","));
+        }
+        Ok(format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day05, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day05, &[Part::Two])
+    }
+}
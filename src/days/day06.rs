@@ -0,0 +1,167 @@
+use crate::*;
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day06;
+
+type Map = (Pose, Grid<bool>);
+
+/// How the patrol is drawn when it's recorded.
+#[derive(Clone, Copy)]
+enum Patrol {
+    Floor,
+    Obstacle,
+    Visited,
+    Guard,
+}
+
+impl Palette for Patrol {
+    fn color(&self) -> Rgb {
+        match self {
+            Patrol::Floor => Rgb::BLACK,
+            Patrol::Obstacle => Rgb::GREY,
+            Patrol::Visited => Rgb::GREEN,
+            Patrol::Guard => Rgb::GOLD,
+        }
+    }
+}
+
+fn find_visited(
+    (guard, obstacles): (Pose, &Grid<bool>),
+    recorder: &mut Recorder,
+) -> Result<HashSet<Coord>> {
+    let mut guard = guard;
+    let mut visited: HashSet<Pose> = HashSet::new();
+
+    while obstacles.contains(guard.pos) {
+        recorder.capture(|| {
+            let mut frame = obstacles.map(|obstacle| match obstacle {
+                true => Patrol::Obstacle,
+                false => Patrol::Floor,
+            });
+            for pose in &visited {
+                frame[pose.pos] = Patrol::Visited;
+            }
+            frame[guard.pos] = Patrol::Guard;
+            frame
+        })?;
+        let newly_added = visited.insert(guard);
+        if !newly_added {
+            return Err(Error::msg("Loop Detected"));
+        }
+        if obstacles.get(guard.ahead()) == Some(&true) {
+            guard = guard.rotate(Direction::turn_right);
+        } else {
+            guard = guard.step();
+        }
+    }
+
+    Ok(HashSet::from_iter(visited.iter().map(|pose| pose.pos)))
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Map;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let map = Grid::from_reader(reader, |chr| chr)?;
+        let mut guards = map.iter().filter_map(|(pos, chr)| {
+            Some(Pose {
+                pos,
+                dir: Direction::try_from(*chr).ok()?,
+            })
+        });
+        let guard = guards.next().context("No guard in input!")?;
+        ensure!(guards.next().is_none(), "Multiple guards!");
+
+        Ok((guard, map.map(|chr| *chr == '#')))
+    }
+
+    fn part1((guard, obstacles): &Self::Input, _params: &Params) -> Result<Answer> {
+        let mut recorder = Recorder::start()?;
+        let visited = find_visited((*guard, obstacles), &mut recorder)?;
+        recorder.finish()?;
+
+        Ok(visited.len().into())
+    }
+
+    fn part2((guard, obstacles): &Self::Input, _params: &Params) -> Result<Answer> {
+        let visited = find_visited((*guard, obstacles), &mut Recorder::off())?;
+
+        let loop_obstacles: HashSet<_> =
+            HashSet::from_iter(visited.iter().filter(|possible_obstacle| {
+                if **possible_obstacle == guard.pos {
+                    return false;
+                }
+
+                let mut obstacles = obstacles.to_owned();
+                if obstacles[**possible_obstacle] {
+                    panic!("Re-added existing obstacle!");
+                }
+                obstacles[**possible_obstacle] = true;
+
+                // let obstacles = obstacles
+                //     .union(&HashSet::from([**possible_obstacle]))
+                //     .map(|v| v.to_owned())
+                //     .collect::<HashSet<_>>();
+
+                // let chained = obstacles.iter().chain(vec![*possible_obstacle]);
+                // let obstacles = HashSet::from(chained);
+
+                find_visited((*guard, &obstacles), &mut Recorder::off()).is_err()
+            }));
+
+        debug!(
+            "Obstacles that would trap the guard:\n{}",
+            Picture::new()
+                .size(obstacles.width(), obstacles.height())
+                .layer('#', obstacles.positions(|obstacle| *obstacle))
+                .layer('X', visited.iter().copied())
+                .colored_layer('O', Rgb::RED, loop_obstacles.iter().map(|coord| **coord))
+                .colored_layer('^', Rgb::GOLD, [guard.pos])
+                .axes()
+        );
+
+        Ok(loop_obstacles.len().into())
+    }
+
+    /// A `size` by `size` lab with scattered obstacles, tried again until the guard walks out.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        loop {
+            let mut lab = Grid::new(size, size, '.');
+            for coord in lab.coords().collect::<Vec<_>>() {
+                if rng.chance(0.1) {
+                    lab[coord] = '#';
+                }
+            }
+            let guard = Coord {
+                x: rng.below(size) as isize,
+                y: rng.below(size) as isize,
+            };
+            lab[guard] = '^';
+
+            let text = format!("{}\n", lab);
+            let (guard, obstacles) = <Self as Solution>::parse(text.as_bytes())?;
+            if find_visited((guard, &obstacles), &mut Recorder::off()).is_ok() {
+                return Ok(text);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day06, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day06, &[Part::Two])
+    }
+}
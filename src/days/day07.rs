@@ -0,0 +1,139 @@
+use crate::*;
+use anyhow::*;
+use itertools::{chain, Itertools};
+use std::io::BufRead;
+
+pub struct Day07;
+
+pub struct Equation {
+    test_val: usize,
+    numbers: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Identity,
+    Multiply,
+    Addition,
+    Concatenation,
+}
+
+impl Operation {
+    /// `None` if the result doesn't fit, which is then too big to be any test value.
+    fn calc(self, a: usize, b: usize) -> Option<usize> {
+        match self {
+            Operation::Identity => Some(b),
+            Operation::Multiply => a.checked_mul(b),
+            Operation::Addition => a.checked_add(b),
+            Operation::Concatenation => a
+                .checked_mul(10usize.checked_pow(b.checked_ilog10().unwrap_or(0) + 1)?)?
+                .checked_add(b),
+        }
+    }
+}
+
+fn do_calculation<'a>(
+    equations: impl Iterator<Item = &'a Equation>,
+    operations: Vec<Operation>,
+) -> usize {
+    equations
+        .filter(|Equation { test_val, numbers }| {
+            let operator_count = numbers.len().saturating_sub(1);
+            let mut multi_prod = (0..operator_count)
+                .map(|_| &operations)
+                .multi_cartesian_product()
+                .map(|v| chain![&[Operation::Identity], v]);
+
+            multi_prod.any(|operations| {
+                let val = numbers
+                    .iter()
+                    .zip(operations)
+                    .try_fold(0, |a, (b, op)| op.to_owned().calc(a, *b));
+                val == Some(*test_val)
+            })
+        })
+        .map(
+            |Equation {
+                 test_val,
+                 numbers: _,
+             }| test_val,
+        )
+        .sum()
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<Equation>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let equation = pair(": ", int, list(" ", int));
+
+        Ok(parse_all(
+            &input,
+            lines(map(equation, |(test_val, numbers)| Equation {
+                test_val,
+                numbers,
+            })),
+        )?)
+    }
+
+    fn part1(equations: &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(do_calculation(
+            equations.iter(),
+            vec![Operation::Multiply, Operation::Addition],
+        )
+        .into())
+    }
+
+    fn part2(equations: &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(do_calculation(
+            equations.iter(),
+            vec![
+                Operation::Multiply,
+                Operation::Addition,
+                Operation::Concatenation,
+            ],
+        )
+        .into())
+    }
+
+    /// `size` equations, about half of which some choice of operators solves.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let mut equations = String::new();
+        for _ in 0..size {
+            let numbers = (0..rng.range(2..=6))
+                .map(|_| rng.range(1..=99))
+                .collect_vec();
+            let mut test_val = numbers[0];
+            for number in &numbers[1..] {
+                test_val = match rng.below(3) {
+                    0 => test_val + number,
+                    1 => test_val * number,
+                    _ => format!("{}{}", test_val, number).parse()?,
+                };
+            }
+            if rng.chance(0.5) {
+                test_val += 1;
+            }
+            equations += &format!("{}: {}\n", test_val, numbers.iter().join(" "));
+        }
+        Ok(equations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day07, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day07, &[Part::Two])
+    }
+}
@@ -0,0 +1,110 @@
+use crate::*;
+use anyhow::*;
+use itertools::{chain, Itertools};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::iter::successors;
+
+pub struct Day08;
+
+fn antenna_positions(map: &Grid<char>) -> HashMap<char, Vec<Coord>> {
+    map.iter()
+        .filter(|(_, char)| **char != '.')
+        .map(|(coord, char)| (*char, coord))
+        .into_group_map()
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Grid<char>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::from_reader(reader, |char| char)
+    }
+
+    fn part1(map: &Self::Input, _params: &Params) -> Result<Answer> {
+        let antenna_positions = antenna_positions(map);
+        let antinodes = antenna_positions
+            .values()
+            .flat_map(|v| {
+                v.iter()
+                    .tuple_combinations()
+                    .flat_map(|(a, b)| {
+                        let delta = *a - *b;
+                        vec![*a + delta, *b - delta]
+                    })
+                    .filter(|coord| map.contains(*coord))
+                    .unique()
+            })
+            .unique();
+
+        Ok(antinodes.count().into())
+    }
+
+    fn part2(map: &Self::Input, _params: &Params) -> Result<Answer> {
+        let antenna_positions = antenna_positions(map);
+        let antinodes = antenna_positions
+            .values()
+            .flat_map(|v| {
+                v.iter()
+                    .tuple_combinations()
+                    .flat_map(|(a, b)| {
+                        // Every grid point on the line counts, including ones between the antennas
+                        let step = (*a - *b).gcd_normalized();
+                        let forwards = successors(Some(*a), move |coord| Some(*coord + step));
+                        let backwards =
+                            successors(Some(*a - step), move |coord| Some(*coord - step));
+                        chain(
+                            forwards.take_while(|coord| map.contains(*coord)),
+                            backwards.take_while(|coord| map.contains(*coord)),
+                        )
+                    })
+                    .unique()
+            })
+            .unique();
+
+        Ok(antinodes.count().into())
+    }
+
+    /// A `size` by `size` map with a few antennas on each of about `size / 4` frequencies.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let frequencies = chain!('0'..='9', 'a'..='z', 'A'..='Z').collect_vec();
+        let mut map = Grid::new(size, size, '.');
+        for _ in 0..(size / 4 + 1).min(frequencies.len()) {
+            let frequency = *rng.pick(&frequencies);
+            for _ in 0..rng.range(2..=4) {
+                let antenna = Coord {
+                    x: rng.below(size) as isize,
+                    y: rng.below(size) as isize,
+                };
+                if map[antenna] == '.' {
+                    map[antenna] = frequency;
+                }
+            }
+        }
+        Ok(format!("{}\n", map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day08, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day08, &[Part::Two])
+    }
+
+    #[test]
+    fn part2_counts_points_between_antennas() -> Result<()> {
+        let map = <Day08 as Solution>::parse("a....\n.....\n..a..\n.....\n.....\n".as_bytes())?;
+        assert_eq!(Day08::part2(&map, &Params::default())?, 5usize);
+        Ok(())
+    }
+}
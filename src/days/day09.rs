@@ -0,0 +1,302 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::fmt::Display;
+use std::io::BufRead;
+
+pub struct Day09;
+
+#[derive(Clone)]
+pub struct Chunk {
+    content: Vec<usize>,
+    after: u32,
+}
+
+impl Chunk {
+    fn pop_block(&mut self) -> Result<usize> {
+        let popped = self.content.pop();
+        match popped {
+            Some(popped) => {
+                self.after += 1;
+                Ok(popped)
+            }
+            None => Err(Error::msg("No content to pop")),
+        }
+    }
+
+    fn push_block(&mut self, block: usize) -> Result<()> {
+        if self.after == 0 {
+            return Err(Error::msg("Cannot push to full block"));
+        }
+        self.content.push(block);
+        self.after -= 1;
+        Ok(())
+    }
+}
+
+impl Display for Chunk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            self.content.iter().join(""),
+            (0..self.after).map(|_| '.').join("")
+        )
+    }
+}
+
+fn checksum(chunks: Vec<Chunk>) -> usize {
+    let zero_arr: [usize; 1] = [0];
+    let blocks = chunks.iter().flat_map(|chunk| {
+        let content = chunk.content.iter();
+        let after = zero_arr
+            .iter()
+            .cycle()
+            .take(chunk.after.try_into().unwrap());
+        content.chain(after).collect_vec()
+    });
+
+    blocks
+        .enumerate()
+        .fold(0, |acc, (position, id)| acc + position * *id)
+}
+
+fn chunk_has_content(chunk: &Chunk) -> bool {
+    !chunk.content.is_empty()
+}
+
+fn chunk_has_space(chunk: &Chunk) -> bool {
+    chunk.after > 0
+}
+
+fn more_to_process(chunks: &[Chunk]) -> bool {
+    // A disk with no files or no free space is already compact
+    let Some((pop_idx, _)) = chunks
+        .iter()
+        .rev()
+        .find_position(|chunk| chunk_has_content(chunk))
+    else {
+        return false;
+    };
+    let pop_idx = chunks.len() - pop_idx - 1;
+    let Some((push_idx, _)) = chunks.iter().find_position(|chunk| chunk_has_space(chunk)) else {
+        return false;
+    };
+    pop_idx > push_idx
+}
+
+fn find_chunk_span_fits(chunks: &[Chunk], file_to_move: &Chunk) -> Option<usize> {
+    chunks
+        .iter()
+        .find_position(|chunk| chunk.after >= file_to_move.content.len().try_into().unwrap())
+        .map(|(idx, _)| idx)
+}
+
+/// Lays the disk out block by block and moves one block (or in part 2, one whole file) at a
+/// time, as the puzzle draws it, to check the chunk compaction against.
+fn reference_checksum(chunks: &[Chunk], part: Part) -> usize {
+    let mut disk: Vec<Option<usize>> = Vec::new();
+    for chunk in chunks {
+        disk.extend(chunk.content.iter().map(|id| Some(*id)));
+        disk.extend((0..chunk.after).map(|_| None));
+    }
+
+    match part {
+        Part::One => {
+            while let Some(gap) = disk.iter().position(Option::is_none) {
+                let Some(last) = disk.iter().rposition(Option::is_some) else {
+                    break;
+                };
+                if last < gap {
+                    break;
+                }
+                disk.swap(gap, last);
+            }
+        }
+        Part::Two => {
+            for id in (0..chunks.len()).rev() {
+                let Some(start) = disk.iter().position(|block| *block == Some(id)) else {
+                    continue;
+                };
+                let length = disk[start..]
+                    .iter()
+                    .take_while(|block| **block == Some(id))
+                    .count();
+                let gap =
+                    (0..start).find(|gap| disk[*gap..*gap + length].iter().all(Option::is_none));
+                if let Some(gap) = gap {
+                    for offset in 0..length {
+                        disk.swap(gap + offset, start + offset);
+                    }
+                }
+            }
+        }
+    }
+
+    disk.iter()
+        .enumerate()
+        .map(|(position, block)| position * block.unwrap_or(0))
+        .sum()
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Chunk>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let disk_map = |span: Span| {
+            let span = span.trim();
+            if span.is_empty() {
+                return Err(span.error("a disk map"));
+            }
+            span.chars()
+                .map(|(chr, at)| chr.to_digit(10).ok_or_else(|| at.error("a digit")))
+                .collect::<std::result::Result<Vec<_>, _>>()
+        };
+        Ok(parse_all(&input, disk_map)?
+            .chunks(2)
+            .enumerate()
+            .map(|(i, chunk)| Chunk {
+                content: vec![i; chunk[0] as usize],
+                after: chunk.get(1).copied().unwrap_or(0),
+            })
+            .collect_vec())
+    }
+
+    fn part1(chunks: &Self::Input, _params: &Params) -> Result<Answer> {
+        let mut chunks = chunks.clone();
+
+        let mut last_popped_chunk_position: usize = 0;
+        let mut last_pushed_chunk_position: usize = 0;
+
+        trace!("{}", chunks.iter().join(""));
+        while more_to_process(&chunks) {
+            let (pop_offset, chunk_to_pop) = chunks
+                .iter_mut()
+                .rev()
+                .skip(last_popped_chunk_position)
+                .find_position(|chunk| chunk_has_content(chunk))
+                .unwrap();
+            last_popped_chunk_position += pop_offset;
+
+            let block = chunk_to_pop.pop_block().unwrap();
+
+            let (push_offset, chunk_to_push) = chunks
+                .iter_mut()
+                .skip(last_pushed_chunk_position)
+                .find_position(|chunk| chunk_has_space(chunk))
+                .unwrap();
+            last_pushed_chunk_position += push_offset;
+
+            chunk_to_push.push_block(block).unwrap();
+            trace!("{}", chunks.iter().join(""));
+        }
+
+        Ok(checksum(chunks).into())
+    }
+
+    fn part2(chunks: &Self::Input, _params: &Params) -> Result<Answer> {
+        let mut chunks = chunks.clone();
+
+        let mut idx = chunks.len() - 1;
+        loop {
+            trace!("{}", chunks.iter().join(""));
+            let file_to_move = &chunks[idx];
+            let fitting_span = find_chunk_span_fits(&chunks, file_to_move);
+            let content_size: u32 = file_to_move.content.len().try_into().unwrap();
+            let fitting_idx = match fitting_span {
+                Some(i) => {
+                    if i >= idx {
+                        trace!(
+                            "No span left of '{}' fits {} blocks. Continuing.",
+                            file_to_move.content[0],
+                            content_size
+                        );
+                        if idx == 0 {
+                            break;
+                        } else {
+                            idx -= 1;
+                            continue;
+                        }
+                    } else {
+                        i
+                    }
+                }
+                None => {
+                    trace!("No span fits {} blocks. Continuing.", content_size);
+
+                    if idx == 0 {
+                        break;
+                    } else {
+                        idx -= 1;
+                        continue;
+                    }
+                }
+            };
+            let content = file_to_move.content.clone();
+            let fitting_chunk = chunks.get_mut(fitting_idx).unwrap();
+            let remaining_after = fitting_chunk.after - content_size;
+            fitting_chunk.after = 0;
+
+            chunks.insert(
+                fitting_idx + 1,
+                Chunk {
+                    content,
+                    after: remaining_after,
+                },
+            );
+
+            let file_to_move = &mut chunks[idx + 1];
+            file_to_move.content.clear();
+            file_to_move.after += content_size;
+        }
+        trace!("{}", chunks.iter().join(""));
+
+        Ok(checksum(chunks).into())
+    }
+
+    /// A disk map of `size` files, each followed by free space but the last.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let mut disk_map = String::new();
+        for file in 0..size {
+            disk_map += &rng.range(1..=9).to_string();
+            if file + 1 < size {
+                disk_map += &rng.range(0..=9).to_string();
+            }
+        }
+        Ok(disk_map + "\n")
+    }
+
+    fn reference(chunks: &Self::Input, part: Part, _params: &Params) -> Option<Result<Answer>> {
+        Some(Ok(reference_checksum(chunks, part).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{check_reference, DiffOptions};
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day09, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day09, &[Part::Two])
+    }
+
+    #[test]
+    fn agrees_with_reference() -> Result<()> {
+        for part in [Part::One, Part::Two] {
+            check_reference(&Day09, &DiffOptions::new(part, 9))?;
+        }
+        // A disk with no free space is already compact
+        let chunks = <Day09 as Solution>::parse("302".as_bytes())?;
+        assert_eq!(Day09::part1(&chunks, &Params::default())?, 7usize);
+        Ok(())
+    }
+}
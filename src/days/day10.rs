@@ -0,0 +1,105 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+
+pub struct Day10;
+
+/// Every cell reachable from the trailhead by climbing one step at a time. Each cell's height
+/// is its distance from the trailhead, so every trail to it is a shortest path.
+fn climb(trailhead: Coord, map: &Grid<u32>) -> search::SearchResult<Coord> {
+    search::bfs(
+        [trailhead],
+        |here| {
+            let uphill = map[*here] + 1;
+            map.neighbors4(*here)
+                .filter(move |neighbor| map[*neighbor] == uphill)
+        },
+        |_| false,
+    )
+}
+
+fn peaks(trails: &search::SearchResult<Coord>, map: &Grid<u32>) -> Vec<Coord> {
+    trails
+        .distances
+        .keys()
+        .filter(|coord| map[**coord] == 9)
+        .copied()
+        .collect_vec()
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = (Vec<Coord>, Grid<u32>);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let map = Grid::try_from_reader(reader, |char| {
+            char.to_digit(10)
+                .with_context(|| format!("Expected a height, found {:?}", char))
+        })?;
+        let trailheads = map.positions(|height| *height == 0).collect_vec();
+        Ok((trailheads, map))
+    }
+
+    fn part1((trailheads, map): &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(trailheads
+            .iter()
+            .map(|trailhead| peaks(&climb(*trailhead, map), map).len())
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2((trailheads, map): &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(trailheads
+            .iter()
+            .map(|trailhead| {
+                let trails = climb(*trailhead, map);
+                peaks(&trails, map)
+                    .iter()
+                    .map(|peak| trails.path_count(peak))
+                    .sum::<usize>()
+            })
+            .sum::<usize>()
+            .into())
+    }
+
+    /// A `size` by `size` map of hills that fall away one step at a time from scattered peaks,
+    /// so there are trails to find, with some heights knocked out of place.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let peaks = (0..size / 5 + 1)
+            .map(|_| Coord {
+                x: rng.below(size) as isize,
+                y: rng.below(size) as isize,
+            })
+            .collect_vec();
+        let mut map = Grid::new(size, size, 0);
+        for coord in map.coords().collect_vec() {
+            let nearest = peaks
+                .iter()
+                .map(|peak| coord.manhattan(*peak))
+                .min()
+                .unwrap();
+            map[coord] = match rng.chance(0.1) {
+                true => rng.range(0..=9) as isize,
+                false => 9usize.saturating_sub(nearest) as isize,
+            };
+        }
+        Ok(format!("{}\n", map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day10, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day10, &[Part::Two])
+    }
+}
@@ -0,0 +1,148 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day11;
+
+fn add_instances_to_key(key: usize, count: usize, map: &mut HashMap<usize, usize>) {
+    let current = *map.get(&key).unwrap_or(&0);
+    map.insert(key, current + count);
+}
+
+fn blink(value_count: HashMap<usize, usize>) -> HashMap<usize, usize> {
+    let stone_values: Vec<_> = value_count.keys().cloned().collect();
+
+    let mut new_count_this_iter: HashMap<usize, usize> = HashMap::new();
+
+    for stone in stone_values {
+        let count = value_count[&stone];
+
+        if stone == 0 {
+            add_instances_to_key(1, count, &mut new_count_this_iter);
+        } else {
+            let stone_log = stone.ilog10();
+            if stone_log % 2 == 1 {
+                let split_point = 10usize.pow(stone_log.div_ceil(2));
+
+                let left = stone / split_point;
+                let right = stone % split_point;
+
+                add_instances_to_key(left, count, &mut new_count_this_iter);
+                add_instances_to_key(right, count, &mut new_count_this_iter);
+            } else {
+                add_instances_to_key(stone * 2024, count, &mut new_count_this_iter);
+            }
+        }
+    }
+
+    new_count_this_iter
+}
+
+fn stones_after_blinks(value_count: &HashMap<usize, usize>, blinks: usize) -> usize {
+    let mut value_count = value_count.clone();
+
+    for i in 0..blinks {
+        trace!("Blink {}: {:?}", i + 1, value_count);
+        value_count = blink(value_count);
+    }
+    trace!("End: {:?}", value_count);
+
+    value_count.values().sum()
+}
+
+/// Blinks every stone one by one as the puzzle tells it, digits and all, to check
+/// `stones_after_blinks` against. Only fit for a few stones and blinks.
+fn reference_stones_after_blinks(value_count: &HashMap<usize, usize>, blinks: usize) -> usize {
+    let mut stones = value_count
+        .iter()
+        .flat_map(|(stone, count)| std::iter::repeat_n(*stone, *count))
+        .collect_vec();
+    for _ in 0..blinks {
+        stones = stones
+            .iter()
+            .flat_map(|stone| {
+                let digits = stone.to_string();
+                if *stone == 0 {
+                    vec![1]
+                } else if digits.len().is_multiple_of(2) {
+                    let (left, right) = digits.split_at(digits.len() / 2);
+                    vec![left.parse().unwrap(), right.parse().unwrap()]
+                } else {
+                    vec![stone * 2024]
+                }
+            })
+            .collect();
+    }
+    stones.len()
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "blinks",
+        description: "How many times the stones change",
+        default: "25",
+        part2_default: Some("75"),
+    }];
+    type Input = HashMap<usize, usize>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let stones: Vec<usize> = parse_all(&input, words(int))?;
+        Ok(stones.into_iter().counts())
+    }
+
+    fn part1(value_count: &Self::Input, params: &Params) -> Result<Answer> {
+        Ok(stones_after_blinks(value_count, params.get("blinks")?).into())
+    }
+
+    fn part2(value_count: &Self::Input, params: &Params) -> Result<Answer> {
+        Ok(stones_after_blinks(value_count, params.get("blinks")?).into())
+    }
+
+    /// `size` stones, a few of them 0.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let stones = (0..size)
+            .map(|_| match rng.chance(0.1) {
+                true => 0,
+                false => rng.range(1..=9_999_999),
+            })
+            .join(" ");
+        Ok(stones + "\n")
+    }
+
+    fn reference(
+        value_count: &Self::Input,
+        _part: Part,
+        params: &Params,
+    ) -> Option<Result<Answer>> {
+        let blinks = match params.get("blinks") {
+            Result::Ok(blinks) => blinks,
+            Err(error) => return Some(Err(error)),
+        };
+        Some(Ok(reference_stones_after_blinks(value_count, blinks).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{check_reference, DiffOptions};
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day11, &[Part::One])
+    }
+
+    #[test]
+    fn agrees_with_reference() -> Result<()> {
+        let options = DiffOptions {
+            overrides: [("blinks".to_string(), "12".to_string())].into(),
+            ..DiffOptions::new(Part::One, 11)
+        };
+        check_reference(&Day11, &options)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::*;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Grid<char>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::from_reader(reader, |plant| plant)
+    }
+
+    fn part1(garden: &Self::Input, _params: &Params) -> Result<Answer> {
+        let (_, regions) = garden.label_regions();
+        Ok(regions
+            .iter()
+            .map(|region| region.area() * region.perimeter)
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(garden: &Self::Input, _params: &Params) -> Result<Answer> {
+        let (_, regions) = garden.label_regions();
+        Ok(regions
+            .iter()
+            .map(|region| region.area() * region.sides)
+            .sum::<usize>()
+            .into())
+    }
+
+    /// A `size` by `size` garden where most plots take after a neighbour, so plants grow in
+    /// patches.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let mut garden = Grid::new(size, size, 'A');
+        for coord in garden.coords().collect::<Vec<_>>() {
+            let neighbors: Vec<char> = [coord - Delta { x: 1, y: 0 }, coord - Delta { x: 0, y: 1 }]
+                .into_iter()
+                .filter_map(|neighbor| garden.get(neighbor).copied())
+                .collect();
+            garden[coord] = match !neighbors.is_empty() && rng.chance(0.8) {
+                true => *rng.pick(&neighbors),
+                false => (b'A' + rng.below(26) as u8) as char,
+            };
+        }
+        Ok(format!("{}\n", garden))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day12, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day12, &[Part::Two])
+    }
+}
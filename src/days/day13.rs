@@ -0,0 +1,296 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+
+pub struct Day13;
+
+/// Parses `X<sign>1, Y<sign>2`, e.g. `X+94, Y+34` or `X=8400, Y=5400`.
+fn xy<'a>(
+    x_prefix: &'static str,
+    y_prefix: &'static str,
+    value: impl Parser<'a, usize> + Copy,
+) -> impl Parser<'a, (usize, usize)> {
+    pair(", ", prefixed(x_prefix, value), prefixed(y_prefix, value))
+}
+
+/// How far a button moves the claw along one axis. Never 0 in the puzzle, and the solution
+/// divides by it.
+fn step(span: Span) -> Result<usize, ParseError> {
+    match int(span)? {
+        0 => Err(span.error("a step above 0")),
+        step => Result::Ok(step),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Button {
+    x_step: usize,
+    y_step: usize,
+}
+
+impl From<(usize, usize)> for Button {
+    fn from((x_step, y_step): (usize, usize)) -> Self {
+        Self { x_step, y_step }
+    }
+}
+
+#[derive(Debug)]
+struct Coord {
+    x: usize,
+    y: usize,
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    a_button: Button,
+    b_button: Button,
+    prize_loc: Coord,
+}
+
+fn parse_input<T: BufRead>(reader: T) -> Result<Vec<Machine>> {
+    let input = std::io::read_to_string(reader)?;
+    let machine = |span| {
+        let machine = record("\n", ": ")(span)?;
+        Result::Ok(Machine {
+            a_button: machine.field("Button A", xy("X+", "Y+", step))?.into(),
+            b_button: machine.field("Button B", xy("X+", "Y+", step))?.into(),
+            prize_loc: machine.field("Prize", xy("X=", "Y=", int))?.into(),
+        })
+    };
+
+    Ok(parse_all(&input, sections(machine))?)
+}
+
+/// How many times part 1 lets each button be pressed.
+const PART1_MAX_PRESSES: usize = 100;
+
+fn attempt_find_min_tokens_to_prize(
+    machine: &Machine,
+    max_presses: Option<usize>,
+) -> Option<usize> {
+    let a_press_numerator = (machine.prize_loc.x * machine.b_button.y_step) as isize
+        - (machine.prize_loc.y * machine.b_button.x_step) as isize;
+
+    let a_press_denominator = (machine.a_button.x_step * machine.b_button.y_step) as isize
+        - (machine.a_button.y_step * machine.b_button.x_step) as isize;
+
+    // Buttons that move in the same direction don't pin down a single answer
+    if a_press_denominator == 0 {
+        return None;
+    }
+
+    if a_press_numerator % a_press_denominator != 0 {
+        return None;
+    }
+    let a_presses = a_press_numerator / a_press_denominator;
+    if a_presses < 0 {
+        return None;
+    }
+    let a_presses = a_presses as usize;
+
+    let b_press_numerator =
+        machine.prize_loc.x as isize - (a_presses * machine.a_button.x_step) as isize;
+
+    if b_press_numerator < 0 {
+        return None;
+    }
+    let b_press_numerator = b_press_numerator as usize;
+
+    if !b_press_numerator.is_multiple_of(machine.b_button.x_step) {
+        return None;
+    }
+    let b_presses = b_press_numerator / machine.b_button.x_step;
+
+    if max_presses.is_some_and(|most| a_presses > most || b_presses > most) {
+        return None;
+    }
+
+    let tokens = 3 * a_presses + b_presses;
+
+    Some(tokens)
+}
+
+/// Sums the tokens for every prize that can be won once each prize is moved `offset` further
+/// along both axes, pressing neither button more than `max_presses` times.
+fn min_tokens_to_all_prizes(
+    machines: &[Machine],
+    offset: usize,
+    max_presses: Option<usize>,
+) -> usize {
+    let machines = machines
+        .iter()
+        .map(
+            |Machine {
+                 a_button,
+                 b_button,
+                 prize_loc,
+             }| Machine {
+                a_button: a_button.clone(),
+                b_button: b_button.clone(),
+                prize_loc: Coord {
+                    x: prize_loc.x + offset,
+                    y: prize_loc.y + offset,
+                },
+            },
+        )
+        .collect_vec();
+
+    machines
+        .iter()
+        .filter_map(|machine| attempt_find_min_tokens_to_prize(machine, max_presses))
+        .sum()
+}
+
+/// Tries every number of A presses, up to the puzzle's 100 a button in part 1, to check
+/// `attempt_find_min_tokens_to_prize` against. Only fit for prizes that are close by.
+fn reference_min_tokens(
+    machines: &[Machine],
+    offset: usize,
+    max_presses: Option<usize>,
+) -> Result<usize> {
+    let mut tokens = 0;
+    for machine in machines {
+        let (a, b) = (&machine.a_button, &machine.b_button);
+        ensure!(
+            a.x_step > 0 && b.x_step > 0,
+            "Buttons always move along X in the puzzle"
+        );
+        ensure!(
+            a.x_step * b.y_step != a.y_step * b.x_step,
+            "Buttons that move the same way aren't handled by the solution either"
+        );
+
+        let prize_x = machine.prize_loc.x + offset;
+        let prize_y = machine.prize_loc.y + offset;
+        let most_a_presses = max_presses.unwrap_or(prize_x / a.x_step);
+        let cheapest = (0..=most_a_presses)
+            .filter_map(|a_presses| {
+                let rest = prize_x.checked_sub(a_presses * a.x_step)?;
+                if !rest.is_multiple_of(b.x_step) {
+                    return None;
+                }
+                let b_presses = rest / b.x_step;
+                let reached = a_presses * a.y_step + b_presses * b.y_step == prize_y;
+                let allowed = max_presses.is_none_or(|most| b_presses <= most);
+                (reached && allowed).then_some(3 * a_presses + b_presses)
+            })
+            .min();
+        tokens += cheapest.unwrap_or(0);
+    }
+    Ok(tokens)
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "offset",
+        description: "How much further away each prize really is, along both axes",
+        default: "0",
+        part2_default: Some("10000000000000"),
+    }];
+    type Input = Vec<Machine>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(machines: &Self::Input, params: &Params) -> Result<Answer> {
+        let offset = params.get("offset")?;
+        Ok(min_tokens_to_all_prizes(machines, offset, Some(PART1_MAX_PRESSES)).into())
+    }
+
+    fn part2(machines: &Self::Input, params: &Params) -> Result<Answer> {
+        Ok(min_tokens_to_all_prizes(machines, params.get("offset")?, None).into())
+    }
+
+    /// `size` claw machines, about half with a prize the buttons can reach, some of those only with
+    /// more than part 1's 100 presses. No machine's buttons move in the same direction, which the
+    /// solution doesn't handle.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let mut machines = Vec::new();
+        while machines.len() < size {
+            let (a_x, a_y) = (rng.range(10..=99), rng.range(10..=99));
+            let (b_x, b_y) = (rng.range(10..=99), rng.range(10..=99));
+            if a_x * b_y == a_y * b_x {
+                continue;
+            }
+            let (prize_x, prize_y) = match rng.chance(0.5) {
+                true => {
+                    let (a, b) = (rng.range(0..=130), rng.range(0..=130));
+                    (a * a_x + b * b_x, a * a_y + b * b_y)
+                }
+                false => (rng.range(1000..=20000), rng.range(1000..=20000)),
+            };
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a_x, a_y, b_x, b_y, prize_x, prize_y
+            ));
+        }
+        Ok(machines.join("\n"))
+    }
+
+    fn reference(machines: &Self::Input, part: Part, params: &Params) -> Option<Result<Answer>> {
+        let offset = match params.get("offset") {
+            Result::Ok(offset) => offset,
+            Err(error) => return Some(Err(error)),
+        };
+        let max_presses = match part {
+            Part::One => Some(PART1_MAX_PRESSES),
+            Part::Two => None,
+        };
+        Some(reference_min_tokens(machines, offset, max_presses).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{check_reference, DiffOptions};
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day13, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day13, &[Part::Two])
+    }
+
+    #[test]
+    fn agrees_with_reference() -> Result<()> {
+        check_reference(&Day13, &DiffOptions::new(Part::One, 13))?;
+        let options = DiffOptions {
+            overrides: [("offset".to_string(), "500".to_string())].into(),
+            ..DiffOptions::new(Part::Two, 13)
+        };
+        check_reference(&Day13, &options)?;
+
+        // Winning with no A presses at all, which the sign check used to turn away
+        let machine = Machine {
+            a_button: (1, 1).into(),
+            b_button: (36, 31).into(),
+            prize_loc: (3240, 2790).into(),
+        };
+        assert_eq!(attempt_find_min_tokens_to_prize(&machine, None), Some(90));
+
+        // 150 presses of A, more than part 1 allows
+        let machine = Machine {
+            a_button: (10, 11).into(),
+            b_button: (12, 10).into(),
+            prize_loc: (1500, 1650).into(),
+        };
+        assert_eq!(attempt_find_min_tokens_to_prize(&machine, Some(100)), None);
+        assert_eq!(attempt_find_min_tokens_to_prize(&machine, None), Some(450));
+        assert_eq!(reference_min_tokens(&[machine], 0, Some(100))?, 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day14;
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: Coord,
+    velocity: Delta,
+}
+
+impl Robot {
+    fn simulate(&self, size: (usize, usize)) -> Robot {
+        Robot {
+            velocity: self.velocity,
+            position: (self.position + self.velocity).rem_euclid(size),
+        }
+    }
+}
+
+fn render_robots(robots: &[Robot], (width, height): (usize, usize)) -> Vec<String> {
+    let mut positions: HashMap<Coord, usize> = HashMap::new();
+    for robot in robots {
+        let count = positions.get(&robot.position).unwrap_or(&0);
+        positions.insert(robot.position, *count + 1);
+    }
+
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    let count = positions.get(&Coord {
+                        x: x.try_into().unwrap(),
+                        y: y.try_into().unwrap(),
+                    });
+                    match count {
+                        Some(count) => count.to_string(),
+                        None => String::from("."),
+                    }
+                })
+                .join("")
+        })
+        .collect()
+}
+
+fn robots_frame(robots: &[Robot], (width, height): (usize, usize)) -> Grid<bool> {
+    let mut frame = Grid::new(width, height, false);
+    for robot in robots {
+        frame[robot.position] = true;
+    }
+    frame
+}
+
+/// Fails for a robot that starts outside the room, which can't happen in the puzzle.
+fn check_in_room(robots: &[Robot], (width, height): (usize, usize)) -> Result<()> {
+    for Robot { position, .. } in robots {
+        ensure!(
+            position.x < width as isize && position.y < height as isize,
+            "A robot starts at {},{}, outside the {} by {} room",
+            position.x,
+            position.y,
+            width,
+            height
+        );
+    }
+    Ok(())
+}
+
+fn safety_factor(robots: &[Robot], (width, height): (usize, usize)) -> usize {
+    let mid_x = width as isize / 2;
+    let mid_y = height as isize / 2;
+    robots
+        .iter()
+        .into_group_map_by(|robot| {
+            let Coord { x, y } = robot.position;
+
+            if y < mid_y {
+                if x < mid_x {
+                    1
+                } else if x > mid_x {
+                    2
+                } else {
+                    0
+                }
+            } else if y > mid_y {
+                if x < mid_x {
+                    3
+                } else if x > mid_x {
+                    4
+                } else {
+                    0
+                }
+            } else {
+                0
+            }
+        })
+        .iter()
+        .filter(|(group, _)| **group != 0)
+        .map(|(_, robots)| robots.len())
+        .product()
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "width",
+            description: "Width of the room the robots move around",
+            default: "101",
+            part2_default: None,
+        },
+        Param {
+            name: "height",
+            description: "Height of the room the robots move around",
+            default: "103",
+            part2_default: None,
+        },
+        Param {
+            name: "seconds",
+            description: "How long the robots move for before the safety factor is taken",
+            default: "100",
+            part2_default: None,
+        },
+    ];
+    type Input = Vec<Robot>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let robot = |span| {
+            let robot = record(" ", "=")(span)?;
+            // Positions can't be negative, unlike velocities
+            let position = robot.field("p", pair(",", int::<usize>, int::<usize>))?;
+            Result::Ok(Robot {
+                position: Coord {
+                    x: position.0 as isize,
+                    y: position.1 as isize,
+                },
+                velocity: robot.field("v", pair(",", int, int))?.into(),
+            })
+        };
+        let robots = parse_all(&input, lines(robot))?;
+        Ok(robots)
+    }
+
+    fn part1(robots: &Self::Input, params: &Params) -> Result<Answer> {
+        let (width, height) = (params.get("width")?, params.get("height")?);
+        check_in_room(robots, (width, height))?;
+        let mut robots = robots.clone();
+
+        let mut recorder = Recorder::start()?;
+        for _second in 0..params.get::<usize>("seconds")? {
+            robots = robots
+                .iter()
+                .map(|robot| robot.simulate((width, height)))
+                .collect_vec();
+            recorder.capture(|| robots_frame(&robots, (width, height)))?;
+        }
+        recorder.finish()?;
+        debug!(
+            "After {} seconds:\n{}",
+            params.get::<usize>("seconds")?,
+            render_robots(&robots, (width, height)).join("\n")
+        );
+
+        let sf = safety_factor(&robots, (width, height));
+
+        Ok(sf.into())
+    }
+
+    fn part2(robots: &Self::Input, params: &Params) -> Result<Answer> {
+        let (width, height) = (params.get("width")?, params.get("height")?);
+        check_in_room(robots, (width, height))?;
+        let mut robots = robots.clone();
+
+        // The picture shows up when the robots bunch into one quadrant, which gives the lowest
+        // safety factor. The answer is when that happens; `-v` shows the picture to check by eye.
+        let mut most_bunched = (usize::MAX, 0, robots.clone());
+
+        let mut recorder = Recorder::start()?;
+        for i in 1..10000 {
+            robots = robots
+                .iter()
+                .map(|robot| robot.simulate((width, height)))
+                .collect_vec();
+            recorder.capture(|| robots_frame(&robots, (width, height)))?;
+
+            let sf = safety_factor(&robots, (width, height));
+            trace!("{}\t{}", i, sf);
+
+            if sf < most_bunched.0 {
+                most_bunched = (sf, i, robots.clone());
+            }
+        }
+
+        recorder.finish()?;
+
+        let (_, second, robots) = most_bunched;
+        debug!(
+            "Lowest safety factor after {} seconds:\n{}",
+            second,
+            render_robots(&robots, (width, height)).join("\n")
+        );
+
+        Ok(second.into())
+    }
+
+    /// `size` robots in a room of the default size.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let mut robots = String::new();
+        for _ in 0..size {
+            robots += &format!(
+                "p={},{} v={},{}\n",
+                rng.range(0..=100),
+                rng.range(0..=102),
+                rng.range(-99..=99),
+                rng.range(-99..=99)
+            );
+        }
+        Ok(robots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day14, &[Part::One])
+    }
+}
@@ -0,0 +1,316 @@
+use crate::*;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day15;
+
+fn gps(Coord { x, y }: Coord) -> isize {
+    y * 100 + x
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Entity {
+    Wall,
+    Box,
+    Robot,
+}
+
+impl Palette for Entity {
+    fn color(&self) -> Rgb {
+        match self {
+            Entity::Wall => Rgb::GREY,
+            Entity::Box => Rgb::GREEN,
+            Entity::Robot => Rgb::GOLD,
+        }
+    }
+}
+
+type Map<T> = Grid<Option<T>>;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Map<Entity>, Coord, Vec<Direction>)> {
+    let input = std::io::read_to_string(reader)?;
+    let (map, moves) = Span::new(&input).split_once("\n\n")?;
+    let map = Grid::try_from_reader(map.as_bytes(), |chr| match chr {
+        '#' => Ok(Some(Entity::Wall)),
+        'O' => Ok(Some(Entity::Box)),
+        '@' => Ok(Some(Entity::Robot)),
+        '.' => Ok(None),
+        _ => bail!("Unexpected map character {:?}", chr),
+    })?;
+    let robot_coordinate = map
+        .positions(|entity| matches!(entity, Some(Entity::Robot)))
+        .next()
+        .context("No robot in the map")?;
+    let moves = moves
+        .chars()
+        .filter(|(chr, _)| !chr.is_whitespace())
+        .map(|(chr, span)| Direction::try_from(chr).map_err(|_| span.error("one of ^>v<")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    trace!("{:?}\n{:?}", robot_coordinate, moves);
+
+    Ok((map, robot_coordinate, moves))
+}
+
+fn try_move(map: &mut Map<Entity>, coordinate: Coord, mov: Direction) -> Option<Coord> {
+    let new_coordinate = coordinate + mov.to_delta();
+
+    let can_move = match map[new_coordinate] {
+        Some(entity) => match entity {
+            Entity::Wall => false,
+            Entity::Box => try_move(map, new_coordinate, mov).is_some(),
+            Entity::Robot => panic!("try_move found a robot entity at a new location!"),
+        },
+        None => true,
+    };
+
+    if !can_move {
+        return None;
+    }
+
+    let this_entity = map[coordinate].take().unwrap();
+    assert!(map[new_coordinate].replace(this_entity).is_none());
+    Some(new_coordinate)
+}
+
+#[derive(Clone, Copy)]
+enum Part2Entity {
+    Wall,
+    Robot,
+    DoubleWideBox(usize),
+}
+
+impl Palette for Part2Entity {
+    fn color(&self) -> Rgb {
+        match self {
+            Part2Entity::Wall => Rgb::GREY,
+            Part2Entity::Robot => Rgb::GOLD,
+            Part2Entity::DoubleWideBox(_) => Rgb::GREEN,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct DoubleWideBox {
+    left: Coord,
+    right: Coord,
+}
+
+fn can_move(
+    map: &Map<Part2Entity>,
+    boxes: &[DoubleWideBox],
+    coordinate: Coord,
+    mov: Direction,
+) -> bool {
+    let mut coords_to_check = vec![coordinate + mov.to_delta()];
+    while let Some(coord) = coords_to_check.pop() {
+        trace!("{:?}", coords_to_check);
+
+        if let Some(entity) = map[coord] {
+            match entity {
+                Part2Entity::Wall => return false,
+                Part2Entity::Robot => panic!("Found a robot in can_move"),
+                Part2Entity::DoubleWideBox(box_index) => match boxes.get(box_index) {
+                    Some(DoubleWideBox { left, right }) => match mov {
+                        Direction::Left => {
+                            coords_to_check.push(*left + mov.to_delta());
+                        }
+                        Direction::Right => {
+                            coords_to_check.push(*right + mov.to_delta());
+                        }
+                        _ => {
+                            coords_to_check.push(*left + mov.to_delta());
+                            coords_to_check.push(*right + mov.to_delta());
+                        }
+                    },
+                    None => panic!("Could not find specified box"),
+                },
+            }
+        }
+    }
+
+    true
+}
+
+fn try_doublewide_move(
+    map: &mut Map<Part2Entity>,
+    boxes: &mut [DoubleWideBox],
+    coordinate: Coord,
+    mov: Direction,
+) -> Option<Coord> {
+    trace!("Attempting doublewide move: {:?} {:?}", coordinate, mov);
+    if !can_move(map, boxes, coordinate, mov) {
+        return None;
+    }
+
+    push(map, boxes, coordinate, mov);
+    Some(coordinate + mov.to_delta())
+}
+
+/// Moves whatever is at `coordinate` a step, after moving whatever is in its way. Only called
+/// once `can_move` has said everything fits.
+fn push(
+    map: &mut Map<Part2Entity>,
+    boxes: &mut [DoubleWideBox],
+    coordinate: Coord,
+    mov: Direction,
+) {
+    let Some(this_entity) = map[coordinate] else {
+        return;
+    };
+    match this_entity {
+        Part2Entity::Wall => panic!("Attempted to move a wall entity"),
+        Part2Entity::Robot => {
+            let new_coordinate = coordinate + mov.to_delta();
+            push(map, boxes, new_coordinate, mov);
+            assert!(map[coordinate].take().is_some());
+            assert!(map[new_coordinate].replace(this_entity).is_none())
+        }
+        Part2Entity::DoubleWideBox(box_index) => {
+            let DoubleWideBox { left, right } = boxes[box_index];
+            let left_new = left + mov.to_delta();
+            let right_new = right + mov.to_delta();
+
+            // Lifted out first, so pushing sideways doesn't run into its own other half
+            assert!(map[left].take().is_some());
+            assert!(map[right].take().is_some());
+            push(map, boxes, left_new, mov);
+            push(map, boxes, right_new, mov);
+            assert!(map[left_new].replace(this_entity).is_none());
+            assert!(map[right_new].replace(this_entity).is_none());
+            boxes[box_index] = DoubleWideBox {
+                left: left_new,
+                right: right_new,
+            };
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = (Map<Entity>, Coord, Vec<Direction>);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1((map, robot_coordinate, moves): &Self::Input, _params: &Params) -> Result<Answer> {
+        let mut map = map.clone();
+        let mut robot_coordinate = *robot_coordinate;
+
+        let mut recorder = Recorder::start()?;
+        recorder.capture(|| map.clone())?;
+        for mov in moves {
+            if let Some(new_coord) = try_move(&mut map, robot_coordinate, *mov) {
+                robot_coordinate = new_coord
+            }
+            recorder.capture(|| map.clone())?;
+        }
+        recorder.finish()?;
+
+        Ok(map
+            .iter()
+            .filter_map(|(coordinate, entity)| match entity {
+                Some(Entity::Box) => Some(gps(coordinate)),
+                _ => None,
+            })
+            .sum::<isize>()
+            .into())
+    }
+
+    fn part2(
+        (singlewide_map, singlewide_robot_coordinate, moves): &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer> {
+        let mut boxes = Vec::new();
+        let mut map = Grid::new(singlewide_map.width() * 2, singlewide_map.height(), None);
+        for (Coord { x: base_x, y }, entity) in singlewide_map.iter() {
+            let left = Coord { x: base_x * 2, y };
+            let right = Coord {
+                x: base_x * 2 + 1,
+                y,
+            };
+
+            match entity {
+                Some(Entity::Wall) => {
+                    map[left] = Some(Part2Entity::Wall);
+                    map[right] = Some(Part2Entity::Wall);
+                }
+                Some(Entity::Box) => {
+                    let box_index = boxes.len();
+                    boxes.push(DoubleWideBox { left, right });
+                    map[left] = Some(Part2Entity::DoubleWideBox(box_index));
+                    map[right] = Some(Part2Entity::DoubleWideBox(box_index));
+                }
+                Some(Entity::Robot) => map[left] = Some(Part2Entity::Robot),
+                None => {}
+            }
+        }
+        let mut robot_coordinate = Coord {
+            x: singlewide_robot_coordinate.x * 2,
+            y: singlewide_robot_coordinate.y,
+        };
+
+        let mut recorder = Recorder::start()?;
+        recorder.capture(|| map.clone())?;
+        for mov in moves {
+            if let Some(new_coord) =
+                try_doublewide_move(&mut map, &mut boxes, robot_coordinate, *mov)
+            {
+                robot_coordinate = new_coord
+            }
+            recorder.capture(|| map.clone())?;
+        }
+        recorder.finish()?;
+        Ok(boxes
+            .iter()
+            .map(|DoubleWideBox { left, right: _ }| gps(*left))
+            .sum::<isize>()
+            .into())
+    }
+
+    /// A warehouse `size` cells across inside its walls, scattered with boxes and a few more
+    /// walls, then ten moves per cell.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        let side = size + 2;
+        let mut warehouse = Grid::new(side, side, '#');
+        for coord in warehouse.coords().collect::<Vec<_>>() {
+            let inside =
+                (1..=size as isize).contains(&coord.x) && (1..=size as isize).contains(&coord.y);
+            if inside {
+                warehouse[coord] = match rng.below(20) {
+                    0 => '#',
+                    1..=4 => 'O',
+                    _ => '.',
+                };
+            }
+        }
+        let robot = Coord {
+            x: 1 + rng.below(size) as isize,
+            y: 1 + rng.below(size) as isize,
+        };
+        warehouse[robot] = '@';
+
+        let moves: Vec<char> = (0..10 * size * size)
+            .map(|_| *rng.pick(&['^', '>', 'v', '<']))
+            .collect();
+        let moves: Vec<String> = moves.chunks(70).map(String::from_iter).collect();
+        Ok(format!("{}\n\n{}\n", warehouse, moves.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day15, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day15, &[Part::Two])
+    }
+}
@@ -0,0 +1,350 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+
+pub struct Day17;
+
+#[derive(Debug)]
+enum ComboOperand {
+    Literal(u8),
+    RegA,
+    RegB,
+    RegC,
+}
+
+impl ComboOperand {
+    /// `at` is where the operand sits in the input, for reporting the reserved operand 7.
+    fn parse(val: u8, at: Span) -> Result<Self, ParseError> {
+        match val {
+            0..=3 => Result::Ok(Self::Literal(val)),
+            4 => Result::Ok(Self::RegA),
+            5 => Result::Ok(Self::RegB),
+            6 => Result::Ok(Self::RegC),
+            _ => Err(at.error("a combo operand from 0 to 6")),
+        }
+    }
+
+    fn get_value(&self, machine: &Machine) -> usize {
+        match self {
+            ComboOperand::Literal(val) => (*val).into(),
+            ComboOperand::RegA => machine.reg_a,
+            ComboOperand::RegB => machine.reg_b,
+            ComboOperand::RegC => machine.reg_c,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
+enum Instruction {
+    /**
+     * The adv instruction (opcode 0) performs division.
+     * The numerator is the value in the A register.
+     * The denominator is found by raising 2 to the power of the instruction's combo operand.
+     * The result of the division operation is truncated to an integer and then written to the A register.
+     */
+    ADV(ComboOperand),
+    /**
+     *  The bxl instruction (opcode 1) calculates the bitwise XOR of register B and the instruction's literal operand, then stores the result in register B.
+     */
+    BXL(u8),
+    /**
+     * The bst instruction (opcode 2) calculates the value of its combo operand modulo 8 (thereby keeping only its lowest 3 bits), then writes that value to the B register.
+     */
+    BST(ComboOperand),
+    /**
+     * The jnz instruction (opcode 3) does nothing if the A register is 0.
+     * However, if the A register is not zero, it jumps by setting the instruction pointer to the value of its literal operand; if this instruction jumps, the instruction pointer is not increased by 2 after this instruction.
+     */
+    JNZ(u8),
+    /**
+     * The bxc instruction (opcode 4) calculates the bitwise XOR of register B and register C, then stores the result in register B.
+     * (For legacy reasons, this instruction reads an operand but ignores it.)
+     */
+    BXC,
+    /**
+     * The out instruction (opcode 5) calculates the value of its combo operand modulo 8, then outputs that value.
+     * (If a program outputs multiple values, they are separated by commas.)
+     */
+    OUT(ComboOperand),
+    /**
+     * The bdv instruction (opcode 6) works exactly like the adv instruction except that the result is stored in the B register.
+     * (The numerator is still read from the A register.)
+     */
+    BDV(ComboOperand),
+    /**
+     * The cdv instruction (opcode 7) works exactly like the adv instruction except that the result is stored in the C register.
+     * (The numerator is still read from the A register.)
+     */
+    CDV(ComboOperand),
+}
+
+impl Instruction {
+    /// Both values are already known to be 3-bit; `at` is where the operand sits. Jumps must land
+    /// on an instruction rather than an operand, so the instruction pointer always stays even.
+    fn parse(instr: u8, op: u8, at: Span) -> Result<Self, ParseError> {
+        Result::Ok(match instr {
+            0 => Self::ADV(ComboOperand::parse(op, at)?),
+            1 => Self::BXL(op),
+            2 => Self::BST(ComboOperand::parse(op, at)?),
+            3 if op % 2 == 1 => return Err(at.error("an even jump target")),
+            3 => Self::JNZ(op),
+            4 => Self::BXC,
+            5 => Self::OUT(ComboOperand::parse(op, at)?),
+            6 => Self::BDV(ComboOperand::parse(op, at)?),
+            _ => Self::CDV(ComboOperand::parse(op, at)?),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    reg_a: usize,
+    reg_b: usize,
+    reg_c: usize,
+    output: Vec<u8>,
+}
+
+/// The program both as decoded instructions and as its values, which part 2 looks for in the
+/// output.
+#[derive(Debug)]
+pub struct Program {
+    values: Vec<u8>,
+    instructions: Vec<Instruction>,
+}
+
+/// An opcode or operand, which are all 3-bit.
+fn three_bit(span: Span) -> Result<u8, ParseError> {
+    match int(span)? {
+        val @ 0..=7 => Result::Ok(val),
+        _ => Err(span.error("a 3-bit value")),
+    }
+}
+
+fn program(span: Span) -> Result<Program, ParseError> {
+    let span = span.trim();
+    let values = list(",", |span| Result::Ok((three_bit(span)?, span)))(span)?;
+    let instructions = values
+        .chunks(2)
+        .map(|chunk| match *chunk {
+            [(instr, _), (op, at)] => Instruction::parse(instr, op, at),
+            _ => Err(span.error_after("\",\" and an operand")),
+        })
+        .collect::<Result<_, _>>()?;
+    Result::Ok(Program {
+        values: values.iter().map(|(val, _)| *val).collect(),
+        instructions,
+    })
+}
+
+fn parse_program<R: BufRead>(reader: R) -> Result<(Machine, Program)> {
+    let input = std::io::read_to_string(reader)?;
+    let registers = |span| {
+        let registers = record("\n", ": ")(span)?;
+        Result::Ok(Machine {
+            reg_a: registers.field("Register A", int)?,
+            reg_b: registers.field("Register B", int)?,
+            reg_c: registers.field("Register C", int)?,
+            output: vec![],
+        })
+    };
+
+    Ok(parse_all(
+        &input,
+        pair(BLANK_LINE, registers, prefixed("Program: ", program)),
+    )?)
+}
+
+fn calc_division(operand: &ComboOperand, machine: &Machine) -> usize {
+    machine.reg_a / 2_usize.pow(operand.get_value(machine).try_into().unwrap())
+}
+
+fn execute(mut machine: Machine, instructions: &[Instruction]) -> Vec<u8> {
+    let mut ip: usize = 0;
+
+    loop {
+        let idx = ip / 2;
+        if idx >= instructions.len() {
+            // HALT
+            break;
+        }
+
+        let instruction = &instructions[idx];
+
+        trace!("\t{:?}\n({}): {:?}", machine, ip, instruction);
+
+        ip += 2;
+
+        match instruction {
+            Instruction::ADV(combo_operand) => {
+                machine.reg_a = calc_division(combo_operand, &machine)
+            }
+            Instruction::BXL(operand) => machine.reg_b ^= *operand as usize,
+            Instruction::BST(combo_operand) => {
+                machine.reg_b = combo_operand.get_value(&machine) & 0b111
+            }
+            Instruction::JNZ(operand) => {
+                if machine.reg_a != 0 {
+                    ip = *operand as usize
+                }
+            }
+            Instruction::BXC => machine.reg_b ^= machine.reg_c,
+            Instruction::OUT(combo_operand) => machine
+                .output
+                .push((combo_operand.get_value(&machine) & 0b111) as u8),
+            Instruction::BDV(combo_operand) => {
+                machine.reg_b = calc_division(combo_operand, &machine)
+            }
+            Instruction::CDV(combo_operand) => {
+                machine.reg_c = calc_division(combo_operand, &machine)
+            }
+        }
+    }
+
+    machine.output
+}
+
+/// Whether the program shifts A right three bits each time round and loops back to the start
+/// until A is 0, as the real inputs do. Then it outputs one value per three bits of A, the last
+/// from the top three.
+fn shifts_a_by_three(instructions: &[Instruction]) -> bool {
+    let shifts = instructions
+        .iter()
+        .filter(|instruction| matches!(instruction, Instruction::ADV(_)))
+        .collect_vec();
+    let jumps = instructions
+        .iter()
+        .filter(|instruction| matches!(instruction, Instruction::JNZ(_)))
+        .count();
+    matches!(shifts[..], [Instruction::ADV(ComboOperand::Literal(3))])
+        && jumps == 1
+        && matches!(instructions.last(), Some(Instruction::JNZ(0)))
+}
+
+/// The lowest A that makes the program output itself, found three bits at a time from the top:
+/// each guess so far must already output the end of the program. Assumes each time round sets B
+/// and C afresh, as the real inputs do.
+fn lowest_self_output(machine: &Machine, program: &Program) -> Option<usize> {
+    fn search(machine: &Machine, program: &Program, high: usize, found: usize) -> Option<usize> {
+        if found == program.values.len() {
+            return Some(high);
+        }
+        let wanted = &program.values[program.values.len() - found - 1..];
+        // Past 64 bits, nothing longer can be output
+        let high = high.checked_mul(8)?;
+        (0..8).find_map(|low| {
+            let a = high + low;
+            let mut start = machine.clone();
+            start.reg_a = a;
+            match a > 0 && execute(start, &program.instructions) == wanted {
+                true => search(machine, program, a, found + 1),
+                false => None,
+            }
+        })
+    }
+    search(machine, program, 0, 0)
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = (Machine, Program);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_program(reader)
+    }
+
+    fn part1((machine, program): &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(execute(machine.clone(), &program.instructions)
+            .into_iter()
+            .join(",")
+            .into())
+    }
+
+    fn part2((machine, program): &Self::Input, _params: &Params) -> Result<Answer> {
+        ensure!(
+            shifts_a_by_three(&program.instructions),
+            "Part 2 only handles programs that shift A right three bits each time round"
+        );
+        let a = lowest_self_output(machine, program)
+            .context("No value of register A makes the program output itself")?;
+        Ok(a.into())
+    }
+
+    /// A program shaped like the real ones: a loop of `size` random instructions, at most five so
+    /// it's no longer than theirs, that outputs a value and shifts A right by three bits until
+    /// it's 0. Divisions only shift by literals, so no step can overflow. Tried again until some
+    /// A makes it output itself, so part 2 has an answer.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        loop {
+            let mut program = Vec::new();
+            for _ in 0..size.min(5) {
+                program.extend(match rng.below(5) {
+                    0 => [1, rng.range(0..=7)],
+                    1 => [2, rng.range(4..=6)],
+                    2 => [4, rng.range(0..=7)],
+                    3 => [6, rng.range(0..=3)],
+                    _ => [7, rng.range(0..=3)],
+                });
+            }
+            program.extend([0, 3, 5, rng.range(4..=6), 3, 0]);
+
+            let text = format!(
+                "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                rng.next_u64() >> 16,
+                program.iter().join(",")
+            );
+            let (machine, program) = <Self as Solution>::parse(text.as_bytes())?;
+            if lowest_self_output(&machine, &program).is_some() {
+                return Ok(text);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day17, &[Part::One])
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        check_examples(&Day17, &[Part::Two])
+    }
+
+    #[test]
+    fn reports_where_a_register_is_malformed() {
+        let example = load_example(17, "1").unwrap();
+        let input = example.input.replace("Register C: 0", "Register C 0");
+        let error = <Day17 as Solution>::parse(input.as_bytes()).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.expected, "\": \"");
+    }
+
+    #[test]
+    fn reports_where_the_program_is_malformed() {
+        let example = load_example(17, "1").unwrap();
+        let error_at = |program: &str| {
+            let input = example.input.replace("0,1,5,4,3,0", program);
+            let error = <Day17 as Solution>::parse(input.as_bytes()).unwrap_err();
+            let error = error.downcast_ref::<ParseError>().unwrap().clone();
+            (error.column, error.expected)
+        };
+        assert_eq!(error_at("0,1,5,8,3,0"), (16, "a 3-bit value".to_string()));
+        assert_eq!(
+            error_at("0,7,5,4"),
+            (12, "a combo operand from 0 to 6".to_string())
+        );
+        assert_eq!(
+            error_at("0,1,5,4,3,1"),
+            (20, "an even jump target".to_string())
+        );
+        assert_eq!(error_at("0,1,5"), (15, "\",\" and an operand".to_string()));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::*;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day19;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Vec<String>, Vec<String>)> {
+    let input = std::io::read_to_string(reader)?;
+    Ok(parse_all(
+        &input,
+        pair(BLANK_LINE, list(", ", string), lines(string)),
+    )?)
+}
+
+fn try_fit_pattern(patterns: &[String], requested_pattern: &str) -> bool {
+    if requested_pattern.is_empty() {
+        return true;
+    }
+
+    trace!("try_fit_pattern({:?}, \"{}\")", patterns, requested_pattern);
+
+    patterns.iter().any(|pattern| {
+        trace!("\t{}?", pattern);
+        if pattern.len() > requested_pattern.len() {
+            return false;
+        }
+        let pattern_match_slice = &requested_pattern[0..pattern.len()];
+        let rest_slice = &requested_pattern[pattern.len()..];
+        trace!("\t\tmatch: {}\trest: {}", pattern_match_slice, rest_slice);
+        pattern_match_slice == *pattern && try_fit_pattern(patterns, rest_slice)
+    })
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = (Vec<String>, Vec<String>);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(
+        (available_patterns, requested_patterns): &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer> {
+        Ok(requested_patterns
+            .iter()
+            .filter(|pattern| try_fit_pattern(available_patterns, pattern))
+            .count()
+            .into())
+    }
+
+    /// `size` different towels and `size` designs, about half made from the towels.
+    fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+        const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+        let mut towels: Vec<String> = Vec::new();
+        while towels.len() < size {
+            let towel = (0..rng.range(1..=8)).map(|_| *rng.pick(&COLORS)).collect();
+            if !towels.contains(&towel) {
+                towels.push(towel);
+            }
+        }
+
+        let mut designs = Vec::new();
+        for _ in 0..size {
+            designs.push(match rng.chance(0.5) {
+                true => (0..rng.range(2..=8))
+                    .map(|_| rng.pick(&towels).as_str())
+                    .collect::<String>(),
+                false => (0..rng.range(20..=60))
+                    .map(|_| *rng.pick(&COLORS))
+                    .collect(),
+            });
+        }
+        Ok(format!("{}\n\n{}\n", towels.join(", "), designs.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&Day19, &[Part::One])
+    }
+}
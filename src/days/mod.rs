@@ -0,0 +1,100 @@
+use crate::AnySolution;
+
+macro_rules! days {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every solved day, in order.
+        pub const ALL: &[&dyn AnySolution] = &[$(&$module::$solution),*];
+    };
+}
+
+days! {
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+    day17::Day17,
+    day19::Day19,
+    day21::Day21,
+}
+
+pub mod template;
+
+pub fn get(day: u8) -> Option<&'static dyn AnySolution> {
+    ALL.iter().find(|solution| solution.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Params, ParseError, Part, Rng};
+
+    #[test]
+    fn generated_inputs_parse_and_solve() {
+        for solution in ALL {
+            for seed in 0..3 {
+                let input = solution.generate(8, &mut Rng::new(seed)).unwrap();
+                let context = format!("Day {} seed {}:\n{}", solution.day(), seed, input);
+                let parsed = solution.parse(&mut input.as_bytes()).expect(&context);
+                for part in [Part::One, Part::Two] {
+                    let params = Params::resolve(solution.params(), part, &Default::default())
+                        .expect(&context);
+                    match solution.solve(parsed.as_ref(), part, &params) {
+                        Err(error) if error.to_string().contains("not solved") => {}
+                        Err(error) => panic!("Part {}, {}\n{:#}", part, context, error),
+                        Ok(_) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_inputs_are_errors_not_panics() {
+        // Each points at where the input goes wrong
+        let unparsable = [
+            (3, "mul(2,3)mul(99999999999,2)\n", (1, 13)),
+            (9, "12x4\n", (1, 3)),
+            (
+                13,
+                "Button A: X+1, Y+1\nButton B: X+0, Y+2\nPrize: X=5, Y=5\n",
+                (2, 13),
+            ),
+            (14, "p=1,1 v=1,1\np=-1,3 v=1,1\n", (2, 3)),
+            (21, "029A\n1x3A\n", (2, 2)),
+        ];
+        for (day, input, at) in unparsable {
+            let error = get(day).unwrap().parse(&mut input.as_bytes()).unwrap_err();
+            let error = error.downcast_ref::<ParseError>().expect(input);
+            assert_eq!((error.line, error.column), at, "Day {}: {}", day, error);
+        }
+
+        // These parse, and must then either solve or fail with an error
+        let unsolvable = [
+            (2, "5\n1 2\n"),
+            (6, ".#.\n#^#\n.#.\n"),
+            (7, "10: 99999999999 99999999999\n"),
+            (14, "p=200,3 v=1,1\n"),
+        ];
+        for (day, input) in unsolvable {
+            let solution = get(day).unwrap();
+            let parsed = solution.parse(&mut input.as_bytes()).unwrap();
+            for part in [Part::One, Part::Two] {
+                let params = Params::resolve(solution.params(), part, &Default::default()).unwrap();
+                let _ = solution.solve(parsed.as_ref(), part, &params);
+            }
+        }
+    }
+}
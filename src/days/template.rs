@@ -0,0 +1,40 @@
+use crate::*;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = 0; // TODO: Fill the day
+    type Input = Vec<String>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(reader.lines().map_while(Result::ok).collect())
+    }
+
+    fn part1(lines: &Self::Input, _params: &Params) -> Result<Answer> {
+        // TODO: Solve Part 1 of the puzzle
+        let answer = lines.len();
+        Ok(answer.into())
+    }
+
+    // fn part2(lines: &Self::Input, _params: &Params) -> Result<Answer> {
+    //     Ok(0.into())
+    // }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::check_examples;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        check_examples(&DayNN, &[Part::One])
+    }
+
+    // #[test]
+    // fn part2_examples() -> Result<()> {
+    //     check_examples(&DayNN, &[Part::Two])
+    // }
+}
@@ -0,0 +1,335 @@
+//! Differential testing: runs a day's parts and its reference solution on many small generated
+//! inputs, and shrinks the first input they disagree on until nothing can be cut from it without
+//! the disagreement going away.
+
+use crate::*;
+use anyhow::*;
+use regex::Regex;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    pub part: Part,
+    /// How many inputs to try. Their sizes cycle from 1 up to `size`, so small ones come first.
+    pub cases: usize,
+    pub size: usize,
+    /// The first case's seed; each case after it uses the next.
+    pub seed: u64,
+    pub overrides: BTreeMap<String, String>,
+}
+
+impl DiffOptions {
+    pub fn new(part: Part, seed: u64) -> Self {
+        Self {
+            part,
+            cases: 100,
+            size: 6,
+            seed,
+            overrides: BTreeMap::new(),
+        }
+    }
+}
+
+/// What one side made of an input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Answer(Answer),
+    Failed(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{}", answer),
+            Outcome::Failed(reason) => write!(f, "failed: {}", reason),
+        }
+    }
+}
+
+/// An input the parts and the reference disagree on, already shrunk.
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    /// The seed of the generated input it was shrunk from.
+    pub seed: u64,
+    pub input: String,
+    pub fast: Outcome,
+    pub reference: Outcome,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Shrunk from seed {}:\n{}\nSolution:  {}\nReference: {}",
+            self.seed,
+            self.input.trim_end(),
+            self.fast,
+            self.reference
+        )
+    }
+}
+
+thread_local! {
+    /// Set while `caught` runs on this thread, so its panics aren't reported.
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook, once, in one that stays quiet on threads inside `caught`. Swapping the
+/// hook for each call instead would race with other threads doing the same.
+fn install_silencing_hook() {
+    static INSTALLED: OnceLock<()> = OnceLock::new();
+    INSTALLED.get_or_init(|| {
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if !SILENCED.get() {
+                hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `run`, turning a panic into an error message. Its panics aren't reported, since
+/// shrinking can panic hundreds of times.
+fn caught<T>(run: impl FnOnce() -> Result<T>) -> std::result::Result<T, String> {
+    install_silencing_hook();
+    let silenced = SILENCED.replace(true);
+    let result = catch_unwind(AssertUnwindSafe(run));
+    SILENCED.set(silenced);
+    match result {
+        std::result::Result::Ok(result) => result.map_err(|error| format!("{:#}", error)),
+        Err(panic) => Err(match panic.downcast_ref::<&str>() {
+            Some(message) => format!("panicked: {}", message),
+            None => match panic.downcast_ref::<String>() {
+                Some(message) => format!("panicked: {}", message),
+                None => "panicked".to_string(),
+            },
+        }),
+    }
+}
+
+fn outcome(run: impl FnOnce() -> Result<Answer>) -> Outcome {
+    match caught(run) {
+        std::result::Result::Ok(answer) => Outcome::Answer(answer),
+        Err(reason) => Outcome::Failed(reason),
+    }
+}
+
+/// Both outcomes for `input`, or `None` if it doesn't parse, since then it isn't a puzzle input.
+fn run_both(
+    solution: &dyn AnySolution,
+    input: &str,
+    part: Part,
+    params: &Params,
+) -> Option<(Outcome, Outcome)> {
+    let parsed = caught(|| solution.parse(&mut input.as_bytes())).ok()?;
+    let fast = outcome(|| solution.solve(parsed.as_ref(), part, params));
+    let reference = outcome(|| {
+        solution
+            .reference(parsed.as_ref(), part, params)
+            .unwrap_or_else(|| bail!("Day {} has no reference solution", solution.day()))
+    });
+    Some((fast, reference))
+}
+
+/// Tries `options.cases` generated inputs, returning the first mismatch, shrunk.
+pub fn compare(solution: &dyn AnySolution, options: &DiffOptions) -> Result<Option<Mismatch>> {
+    ensure!(options.size > 0, "Inputs need a size of at least 1");
+    let params = Params::resolve(solution.params(), options.part, &options.overrides)?;
+
+    for case in 0..options.cases {
+        let seed = options.seed.wrapping_add(case as u64);
+        let size = 1 + case % options.size;
+        let input = solution.generate(size, &mut Rng::new(seed))?;
+        // An input the reference can't answer is one it wasn't written for, like a button that
+        // doesn't move, so it doesn't count against the solution
+        let differs = |input: &str| {
+            run_both(solution, input, options.part, &params).is_some_and(|(fast, reference)| {
+                matches!(reference, Outcome::Answer(_)) && fast != reference
+            })
+        };
+        if case == 0 {
+            // Checked once, rather than reporting every input as a mismatch
+            let parsed = solution.parse(&mut input.as_bytes())?;
+            ensure!(
+                solution
+                    .reference(parsed.as_ref(), options.part, &params)
+                    .is_some(),
+                "Day {} has no reference solution",
+                solution.day()
+            );
+        }
+        if !differs(&input) {
+            continue;
+        }
+
+        let input = shrink(input, differs);
+        let (fast, reference) = run_both(solution, &input, options.part, &params).unwrap();
+        return Ok(Some(Mismatch {
+            seed,
+            input,
+            fast,
+            reference,
+        }));
+    }
+    Ok(None)
+}
+
+/// Fails with the shrunk mismatch if `compare` finds one, for tests and `aoc diff`.
+pub fn check_reference(solution: &dyn AnySolution, options: &DiffOptions) -> Result<()> {
+    match compare(solution, options)? {
+        Some(mismatch) => bail!(
+            "Day {:02} part {} disagrees with its reference\n{}",
+            solution.day(),
+            options.part,
+            mismatch
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Cuts `input` down while `fails` still holds, trying the biggest cuts first.
+fn shrink(input: String, fails: impl Fn(&str) -> bool) -> String {
+    let mut smallest = input;
+    'shrinking: loop {
+        for candidate in smaller(&smallest) {
+            if fails(&candidate) {
+                smallest = candidate;
+                continue 'shrinking;
+            }
+        }
+        return smallest;
+    }
+}
+
+/// Inputs a step smaller than `input`: without one of its blank-line sections, lines, words or
+/// characters, or with one of its numbers halved or one less.
+fn smaller(input: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+    let body = input.trim_end_matches('\n');
+    let ending = &input[body.len()..];
+
+    let mut without = |pieces: &[&str], separator: &str| {
+        if pieces.len() > 1 {
+            for skip in 0..pieces.len() {
+                let rest: Vec<&str> = (0..pieces.len())
+                    .filter(|piece| *piece != skip)
+                    .map(|piece| pieces[piece])
+                    .collect();
+                candidates.push(rest.join(separator) + ending);
+            }
+        }
+    };
+    without(&body.split("\n\n").collect::<Vec<_>>(), "\n\n");
+    without(&body.split('\n').collect::<Vec<_>>(), "\n");
+
+    // Words and characters, each cut from where it stands
+    let mut cuts: Vec<(usize, usize)> = Vec::new();
+    for word in Regex::new(r"[^\s]+").unwrap().find_iter(body) {
+        let after = body[word.end()..].starts_with(' ') as usize;
+        cuts.push((word.start(), word.end() + after));
+    }
+    cuts.extend(
+        body.char_indices()
+            .filter(|(_, chr)| *chr != '\n')
+            .map(|(index, chr)| (index, index + chr.len_utf8())),
+    );
+    for (start, end) in cuts {
+        candidates.push(format!("{}{}{}", &body[..start], &body[end..], ending));
+    }
+
+    for number in Regex::new(r"\d+").unwrap().find_iter(body) {
+        let Result::Ok(value) = number.as_str().parse::<u64>() else {
+            continue;
+        };
+        for lower in [value / 2, value.saturating_sub(1)] {
+            if lower < value {
+                candidates.push(format!(
+                    "{}{}{}{}",
+                    &body[..number.start()],
+                    lower,
+                    &body[number.end()..],
+                    ending
+                ));
+            }
+        }
+    }
+
+    candidates.retain(|candidate| !candidate.trim().is_empty());
+    candidates.dedup();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_to_what_still_fails() {
+        // Fails whenever a number over 10 follows a 7 on the same line
+        let fails = |input: &str| {
+            input.lines().any(|line| {
+                let numbers: Vec<u64> = line
+                    .split(' ')
+                    .filter_map(|word| word.parse().ok())
+                    .collect();
+                numbers.windows(2).any(|pair| pair[0] == 7 && pair[1] > 10)
+            })
+        };
+        let input = "1 2 3\n\n4 7 x 250 9\n8 8\n".to_string();
+        assert!(fails(&input));
+        assert_eq!(shrink(input, fails), "7 11\n");
+    }
+
+    #[test]
+    fn catches_panics_and_only_silences_them_meanwhile() {
+        let result = caught::<()>(|| panic!("boom"));
+        assert_eq!(result.unwrap_err(), "panicked: boom");
+        assert!(!SILENCED.get());
+
+        let nested = caught(|| Ok(caught::<()>(|| bail!("inner")).unwrap_err()));
+        assert_eq!(nested.unwrap(), "inner");
+        assert!(!SILENCED.get());
+    }
+
+    #[test]
+    fn reports_a_planted_mismatch() {
+        struct Broken;
+        impl Solution for Broken {
+            const DAY: u8 = 99;
+            type Input = Vec<u64>;
+
+            fn parse<R: std::io::BufRead>(reader: R) -> Result<Self::Input> {
+                let input = std::io::read_to_string(reader)?;
+                Ok(parse_all(&input, lines(int))?)
+            }
+
+            /// Forgets anything past the third line.
+            fn part1(input: &Self::Input, _params: &Params) -> Result<Answer> {
+                Ok(input.iter().take(3).sum::<u64>().into())
+            }
+
+            fn generate(size: usize, rng: &mut Rng) -> Result<String> {
+                Ok((0..size)
+                    .map(|_| format!("{}\n", rng.range(0..=50)))
+                    .collect())
+            }
+
+            fn reference(input: &Self::Input, _: Part, _: &Params) -> Option<Result<Answer>> {
+                Some(Ok(input.iter().sum::<u64>().into()))
+            }
+        }
+
+        let mismatch = compare(&Broken, &DiffOptions::new(Part::One, 1))
+            .unwrap()
+            .unwrap();
+        assert_eq!(mismatch.input, "0\n0\n0\n1\n");
+        assert_eq!(
+            (mismatch.fast, mismatch.reference),
+            (Outcome::Answer(0.into()), Outcome::Answer(1.into()))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::*;
+use anyhow::*;
+use std::fmt::Display;
+
+/// A compass direction on a grid where y grows downwards. The variants run clockwise from
+/// `Up`, so turning is arithmetic on the discriminant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// All eight directions, clockwise from `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The four orthogonal directions, clockwise from `Up`.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    fn turned(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turned(2)
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.turned(6)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turned(4)
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+
+    pub const fn to_delta(self) -> Delta {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        };
+        Delta { x, y }
+    }
+
+    /// The arrow puzzles draw this direction with (`^>v<` for the cardinal directions).
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::UpRight => '↗',
+            Direction::Right => '>',
+            Direction::DownRight => '↘',
+            Direction::Down => 'v',
+            Direction::DownLeft => '↙',
+            Direction::Left => '<',
+            Direction::UpLeft => '↖',
+        }
+    }
+}
+
+/// Reads a cardinal direction from an arrow (`^>v<`) or a compass letter (`NESW`).
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '^' | 'N' => Ok(Direction::Up),
+            '>' | 'E' => Ok(Direction::Right),
+            'v' | 'S' => Ok(Direction::Down),
+            '<' | 'W' => Ok(Direction::Left),
+            _ => bail!("Expected one of ^>v< or NESW, found {:?}", value),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// Where something is and which way it's facing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pose {
+    pub pos: Coord,
+    pub dir: Direction,
+}
+
+impl Pose {
+    /// The position one step ahead.
+    pub fn ahead(self) -> Coord {
+        self.pos + self.dir.to_delta()
+    }
+
+    pub fn step(self) -> Self {
+        Self {
+            pos: self.ahead(),
+            ..self
+        }
+    }
+
+    /// Turns on the spot with one of `Direction`'s turns, e.g. `pose.rotate(Direction::turn_right)`.
+    pub fn rotate(self, turn: impl FnOnce(Direction) -> Direction) -> Self {
+        Self {
+            dir: turn(self.dir),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_around_the_compass() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::DownRight.reverse(), Direction::UpLeft);
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.to_delta(), -dir.reverse().to_delta());
+        }
+    }
+
+    #[test]
+    fn parses_arrows_and_letters() {
+        let arrows: Vec<_> = "^>v<".chars().map(Direction::try_from).collect();
+        let letters: Vec<_> = "NESW".chars().map(Direction::try_from).collect();
+        for ((arrow, letter), expected) in arrows.iter().zip(&letters).zip(Direction::CARDINAL) {
+            assert_eq!(arrow.as_ref().unwrap(), &expected);
+            assert_eq!(letter.as_ref().unwrap(), &expected);
+        }
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn steps_and_rotates() {
+        let pose = Pose {
+            pos: Coord { x: 2, y: 2 },
+            dir: Direction::Up,
+        };
+        let pose = pose.step().rotate(Direction::turn_right).step();
+        assert_eq!(pose.pos, Coord { x: 3, y: 1 });
+        assert_eq!(pose.dir, Direction::Right);
+    }
+}
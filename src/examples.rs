@@ -0,0 +1,174 @@
+use anyhow::*;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Where each day's examples live, one file per example in `input/examples/NN/`.
+pub const EXAMPLES_DIR: &str = "input/examples";
+
+/// Ends an example file's header. The header is TOML, the rest is the puzzle input as is:
+///
+/// ```text
+/// part1 = "12"
+///
+/// [params]
+/// width = 11
+/// height = 7
+/// ---
+/// p=0,4 v=3,-3
+/// ```
+const HEADER_END: &str = "---";
+
+/// An example input from the puzzle text, with the answer it should give for each part (if known)
+/// and any parameters that differ from the real puzzle's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The file name without `.txt`, e.g. `2` for `input/examples/17/2.txt`.
+    pub name: String,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub params: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Header {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+}
+
+/// Lets an answer be written as `part1 = 480` as well as `part1 = "480"`.
+fn value_to_string(value: toml::Value) -> String {
+    match value {
+        toml::Value::String(text) => text,
+        value => value.to_string(),
+    }
+}
+
+impl Example {
+    pub fn expected(&self, part: crate::Part) -> Option<&str> {
+        match part {
+            crate::Part::One => self.part1.as_deref(),
+            crate::Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    /// Splits an example file into its header and input.
+    pub fn parse(name: &str, text: &str) -> Result<Self> {
+        let (header, input) = match text.split_once(&format!("\n{}\n", HEADER_END)) {
+            Some(parts) => parts,
+            None => text
+                .strip_prefix(&format!("{}\n", HEADER_END))
+                .map(|input| ("", input))
+                .with_context(|| format!("No {:?} line after the header", HEADER_END))?,
+        };
+        let header: Header = toml::from_str(header).context("Could not parse the header")?;
+
+        Ok(Self {
+            name: name.to_string(),
+            input: input.to_string(),
+            part1: header.part1.map(value_to_string),
+            part2: header.part2.map(value_to_string),
+            params: header
+                .params
+                .into_iter()
+                .map(|(key, value)| (key, value_to_string(value)))
+                .collect(),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .with_context(|| format!("{} has no name", path.display()))?;
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        Self::parse(name, &text).with_context(|| format!("In {}", path.display()))
+    }
+}
+
+pub fn examples_dir(day: u8) -> PathBuf {
+    Path::new(EXAMPLES_DIR).join(format!("{:02}", day))
+}
+
+/// Every example listed for the day, in order of name with numbers sorted numerically. A day
+/// without an examples directory has no examples.
+pub fn load_examples(day: u8) -> Result<Vec<Example>> {
+    let dir = examples_dir(day);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = Vec::new();
+    for entry in
+        std::fs::read_dir(&dir).with_context(|| format!("Could not read {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            paths.push(path);
+        }
+    }
+    let mut examples = paths
+        .iter()
+        .map(|path| Example::load(path))
+        .collect::<Result<Vec<_>>>()?;
+    examples.sort_by_key(|example| (example.name.parse::<usize>().ok(), example.name.clone()));
+    Ok(examples)
+}
+
+/// The day's example called `name`.
+pub fn load_example(day: u8, name: &str) -> Result<Example> {
+    let path = examples_dir(day).join(format!("{}.txt", name));
+    ensure!(
+        path.exists(),
+        "Day {} has no example {:?}; the examples are {:?}",
+        day,
+        name,
+        load_examples(day)?
+            .iter()
+            .map(|example| example.name.as_str())
+            .collect::<Vec<_>>()
+    );
+    Example::load(&path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn reads_the_header() {
+        let example = Example::parse(
+            "1",
+            "part1 = 12\npart2 = \"4,6\"\n\n[params]\nwidth = 11\n---\n#..\n---\n",
+        )
+        .unwrap();
+        assert_eq!(example.expected(Part::One), Some("12"));
+        assert_eq!(example.expected(Part::Two), Some("4,6"));
+        assert_eq!(example.params["width"], "11");
+        assert_eq!(example.input, "#..\n---\n");
+
+        let example = Example::parse("bare", "---\n#..\n").unwrap();
+        assert_eq!((example.part1, example.input.as_str()), (None, "#..\n"));
+
+        assert!(Example::parse("typo", "prat1 = 12\n---\n").is_err());
+        assert!(Example::parse("headless", "#..\n").is_err());
+    }
+
+    #[test]
+    fn loads_every_example_in_order() {
+        let names: Vec<_> = load_examples(12)
+            .unwrap()
+            .into_iter()
+            .map(|example| example.name)
+            .collect();
+        assert_eq!(names, ["1", "2", "3", "4", "5"]);
+        assert!(load_examples(0).unwrap().is_empty());
+        assert!(load_example(17, "3").is_err());
+    }
+}
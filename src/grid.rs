@@ -0,0 +1,254 @@
+use crate::*;
+use anyhow::*;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+/// A dense rectangular grid indexed by `Coord`, with (0, 0) at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self> {
+        let mut grid = Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for row in rows {
+            if grid.height == 0 {
+                grid.width = row.len();
+            }
+            ensure!(
+                row.len() == grid.width,
+                "Row {} has {} cells, expected {}",
+                grid.height + 1,
+                row.len(),
+                grid.width
+            );
+            grid.cells.extend(row);
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    /// Reads rows of characters up to the first blank line or the end of the input, mapping
+    /// each character to a cell. Pass `&mut reader` to keep reading whatever follows the grid.
+    pub fn try_from_reader<R: BufRead>(
+        reader: R,
+        mut cell: impl FnMut(char) -> Result<T>,
+    ) -> Result<Self> {
+        let mut rows = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.is_empty() {
+                break;
+            }
+            rows.push(
+                line.chars()
+                    .map(&mut cell)
+                    .collect::<Result<Vec<_>>>()
+                    .with_context(|| format!("Row {}", rows.len() + 1))?,
+            );
+        }
+        Self::from_rows(rows)
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R, mut cell: impl FnMut(char) -> T) -> Result<Self> {
+        Self::try_from_reader(reader, |chr| Ok(cell(chr)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, Coord { x, y }: Coord) -> bool {
+        (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y)
+    }
+
+    fn offset(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then(|| coord.y as usize * self.width + coord.x as usize)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.offset(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.offset(coord).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Coord { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero size, and a zero-width grid has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// The in-bounds orthogonal neighbours of `coord`: up, right, down, left.
+    pub fn neighbors4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::CARDINAL
+            .iter()
+            .map(move |dir| coord + dir.to_delta())
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The in-bounds neighbours of `coord` including diagonals, clockwise from up.
+    pub fn neighbors8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .iter()
+            .map(move |dir| coord + dir.to_delta())
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The first position, row by row, holding `value`.
+    pub fn find(&self, value: T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.positions(|cell| *cell == value).next()
+    }
+
+    /// Every position whose cell matches `predicate`, row by row.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coord> + 'a {
+        self.iter()
+            .filter_map(move |(coord, cell)| predicate(cell).then_some(coord))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", coord, width, height))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", coord, width, height))
+    }
+}
+
+/// One line per row with no trailing newline, so a `Grid<char>` prints as it was read.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "\
+#.^
+..#
+
+ignored
+";
+
+    #[test]
+    fn reads_up_to_blank_line() {
+        let mut reader = TEST.as_bytes();
+        let grid = Grid::from_reader(&mut reader, |chr| chr).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), "#.^\n..#");
+        assert_eq!(grid.find('^'), Some(Coord { x: 2, y: 0 }));
+        assert_eq!(reader, b"ignored\n");
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert!(Grid::from_reader("##\n#\n".as_bytes(), |chr| chr).is_err());
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = Grid::from_reader(TEST.as_bytes(), |chr| chr == '#').unwrap();
+        assert_eq!(grid.get(Coord { x: 2, y: 1 }), Some(&true));
+        assert_eq!(grid.get(Coord { x: -1, y: 0 }), None);
+        assert_eq!(grid.get(Coord { x: 3, y: 0 }), None);
+        assert_eq!(grid.get(Coord { x: 0, y: 2 }), None);
+    }
+
+    #[test]
+    fn lists_neighbors_in_bounds() {
+        let grid = Grid::new(3, 2, 0);
+        let corner = Coord { x: 0, y: 0 };
+        assert_eq!(
+            grid.neighbors4(corner).collect::<Vec<_>>(),
+            [Coord { x: 1, y: 0 }, Coord { x: 0, y: 1 }]
+        );
+        assert_eq!(grid.neighbors8(corner).count(), 3);
+        assert_eq!(grid.neighbors8(Coord { x: 1, y: 0 }).count(), 5);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = Grid::from_rows([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [[1, 4], [2, 5], [3, 6]]
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::*;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+pub const GUESSES_FILE: &str = "input/guesses.toml";
+
+/// Which way the puzzle site said a wrong answer was off, when it said.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Guess {
+    answer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hint: Option<Hint>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DayGuesses {
+    #[serde(default)]
+    part1: Vec<Guess>,
+    #[serde(default)]
+    part2: Vec<Guess>,
+}
+
+/// Answers the puzzle site rejected, keyed by zero-padded day like the known answers.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Guesses {
+    days: BTreeMap<String, DayGuesses>,
+}
+
+impl Guesses {
+    /// Loads the guesses file, treating a missing file as having no guesses yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(path)?;
+        toml::from_str(&text).with_context(|| format!("Could not parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn part(&self, day: u8, part: Part) -> &[Guess] {
+        match (self.days.get(&day_key(day)), part) {
+            (Some(guesses), Part::One) => &guesses.part1,
+            (Some(guesses), Part::Two) => &guesses.part2,
+            (None, _) => &[],
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer, hint: Option<Hint>) {
+        let guesses = self.days.entry(day_key(day)).or_default();
+        let guesses = match part {
+            Part::One => &mut guesses.part1,
+            Part::Two => &mut guesses.part2,
+        };
+        guesses.push(Guess {
+            answer: answer.to_string(),
+            hint,
+        });
+    }
+
+    /// Why `answer` can't be right, if an earlier guess already shows it: it was guessed
+    /// before, or it's past a guess that was too high or too low.
+    pub fn rule_out(&self, day: u8, part: Part, answer: &Answer) -> Option<String> {
+        let text = answer.to_string();
+        let number = answer.as_integer();
+        self.part(day, part).iter().find_map(|guess| {
+            if guess.answer == text {
+                return Some(match guess.hint {
+                    Some(hint) => format!("{} was already guessed, and it was {}", text, hint),
+                    None => format!("{} was already guessed, and it was wrong", text),
+                });
+            }
+            let past = match (number, guess.answer.parse::<i128>().ok(), guess.hint?) {
+                (Some(number), Some(guessed), Hint::TooHigh) => number >= guessed,
+                (Some(number), Some(guessed), Hint::TooLow) => number <= guessed,
+                _ => false,
+            };
+            past.then(|| {
+                format!(
+                    "{} can't be right, as {} was already {}",
+                    text,
+                    guess.answer,
+                    guess.hint.unwrap()
+                )
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_out_repeats_and_answers_past_a_bound() {
+        let mut guesses = Guesses::default();
+        guesses.record(7, Part::One, &100usize.into(), Some(Hint::TooHigh));
+        guesses.record(7, Part::One, &20usize.into(), Some(Hint::TooLow));
+        guesses.record(7, Part::Two, &Answer::from("abc"), None);
+
+        let text = toml::to_string(&guesses).unwrap();
+        let guesses: Guesses = toml::from_str(&text).unwrap();
+
+        let rule_out = |part, answer: Answer| guesses.rule_out(7, part, &answer);
+        assert_eq!(
+            rule_out(Part::One, 100usize.into()).unwrap(),
+            "100 was already guessed, and it was too high"
+        );
+        assert_eq!(
+            rule_out(Part::One, 150usize.into()).unwrap(),
+            "150 can't be right, as 100 was already too high"
+        );
+        assert!(rule_out(Part::One, 3usize.into()).is_some());
+        assert_eq!(rule_out(Part::One, 50usize.into()), None);
+        assert!(rule_out(Part::Two, Answer::from("abc")).is_some());
+        assert_eq!(rule_out(Part::Two, 100usize.into()), None);
+        assert_eq!(guesses.rule_out(8, Part::One, &100usize.into()), None);
+    }
+}
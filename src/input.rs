@@ -0,0 +1,136 @@
+use crate::*;
+use anyhow::*;
+use std::fmt::Display;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Overrides where every day's input is read from, like `--input`. `{day}` is replaced with
+/// the two-digit day, so `AOC_INPUT=other/{day}.txt` points `run all` at another account's inputs.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// One of the day's examples in `input/examples/NN/`, by name.
+    Example(String),
+}
+
+impl InputSource {
+    /// Picks the source for `day`: a requested example, then a path given on the command line
+    /// (`-` for stdin), then the `AOC_INPUT` environment variable, then `input/NN.txt`.
+    pub fn resolve(day: u8, path: Option<&str>, example: Option<&str>) -> Self {
+        if let Some(example) = example {
+            return Self::Example(example.to_string());
+        }
+        let path = path.map(str::to_string).or_else(|| {
+            std::env::var(INPUT_ENV)
+                .ok()
+                .filter(|path| !path.is_empty())
+        });
+        match path.as_deref() {
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(path.replace("{day}", &format!("{:02}", day)).into()),
+            None => Self::default_for(day),
+        }
+    }
+
+    /// The day's real puzzle input, `input/NN.txt`, whatever `AOC_INPUT` says.
+    pub fn default_for(day: u8) -> Self {
+        Self::File(runner::input_path(day).into())
+    }
+
+    /// Whether this is the day's real puzzle input, the one its known answers are for.
+    pub fn is_default(&self, day: u8) -> bool {
+        *self == Self::default_for(day)
+    }
+
+    /// Reads the whole input, failing if there's nothing there to solve.
+    pub fn read(&self, solution: &dyn AnySolution) -> Result<String> {
+        let text = match self {
+            Self::File(path) => {
+                ensure!(
+                    path.exists(),
+                    "{} doesn't exist; save day {}'s puzzle input there, or pass another path",
+                    path.display(),
+                    solution.day()
+                );
+                std::fs::read_to_string(path)
+                    .with_context(|| format!("Could not read {}", path.display()))?
+            }
+            Self::Stdin => {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .context("Could not read stdin")?;
+                text
+            }
+            Self::Example(name) => load_example(solution.day(), name)?.input,
+        };
+        ensure!(!text.trim().is_empty(), "{} is empty", self);
+        Ok(text)
+    }
+
+    /// Reads and parses the input, naming this source in any parse error.
+    pub fn parse(&self, solution: &dyn AnySolution) -> Result<Box<dyn std::any::Any>> {
+        let text = self.read(solution)?;
+        solution
+            .parse(&mut text.as_bytes())
+            .map_err(|error| ParseError::name_input(error, &self.to_string()))
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Example(name) => write!(f, "example {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_in_priority_order() {
+        assert_eq!(
+            InputSource::resolve(7, Some("-"), Some("2")),
+            InputSource::Example("2".to_string())
+        );
+        assert_eq!(InputSource::resolve(7, Some("-"), None), InputSource::Stdin);
+        assert_eq!(
+            InputSource::resolve(7, Some("alt/{day}.txt"), None),
+            InputSource::File("alt/07.txt".into())
+        );
+
+        assert!(InputSource::default_for(7).is_default(7));
+        assert!(!InputSource::default_for(7).is_default(8));
+        assert!(!InputSource::File("other.txt".into()).is_default(7));
+        assert!(!InputSource::Stdin.is_default(7));
+    }
+
+    #[test]
+    fn explains_missing_and_empty_inputs() {
+        let solution = days::get(1).unwrap();
+        let missing = InputSource::File("input/does-not-exist.txt".into());
+        let error = missing.read(solution).unwrap_err().to_string();
+        assert!(error.starts_with("input/does-not-exist.txt doesn't exist"));
+
+        let empty = std::env::temp_dir().join("aoc-empty-input.txt");
+        std::fs::write(&empty, "\n").unwrap();
+        let error = InputSource::File(empty.clone()).read(solution).unwrap_err();
+        assert_eq!(error.to_string(), format!("{} is empty", empty.display()));
+
+        assert!(InputSource::Example("3".to_string())
+            .read(solution)
+            .is_err());
+        let example = InputSource::Example("1".to_string())
+            .read(solution)
+            .unwrap();
+        assert!(example.starts_with("3   4\n"));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::*;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+pub const ANSWERS_FILE: &str = "input/answers.toml";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Confirmed answers for each day's real input, keyed by zero-padded day (`[07]`).
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KnownAnswers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+pub(crate) fn day_key(day: u8) -> String {
+    format!("{:02}", day)
+}
+
+impl KnownAnswers {
+    /// Loads the answers file, treating a missing file as having no answers yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(path)?;
+        toml::from_str(&text).with_context(|| format!("Could not parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<Answer> {
+        let answers = self.days.get(&day_key(day))?;
+        let answer = match part {
+            Part::One => answers.part1.as_ref(),
+            Part::Two => answers.part2.as_ref(),
+        }?;
+        Some(answer.parse().unwrap())
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer) {
+        let answers = self.days.entry(day_key(day)).or_default();
+        let slot = match part {
+            Part::One => &mut answers.part1,
+            Part::Two => &mut answers.part2,
+        };
+        *slot = Some(answer.to_string());
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == *answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut known = KnownAnswers::default();
+        known.record(7, Part::One, &3749usize.into());
+        known.record(17, Part::One, &Answer::from("4,6,3,5".to_string()));
+
+        let text = toml::to_string(&known).unwrap();
+        let known: KnownAnswers = toml::from_str(&text).unwrap();
+
+        assert_eq!(known.check(7, Part::One, &3749usize.into()), Verdict::Pass);
+        assert_eq!(
+            known.check(7, Part::One, &3748usize.into()),
+            Verdict::Fail {
+                expected: 3749usize.into()
+            }
+        );
+        assert_eq!(known.check(7, Part::Two, &0usize.into()), Verdict::Unknown);
+        assert_eq!(known.get(17, Part::One).unwrap(), "4,6,3,5");
+    }
+}
@@ -1,7 +1,46 @@
-use std::{
-    collections::HashSet,
-    ops::{Add, Index, Mul, Neg, Sub},
-};
+use std::ops::{Add, Mul, Neg, Sub};
+
+mod answer;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod differential;
+mod direction;
+mod examples;
+mod grid;
+mod guesses;
+mod input;
+mod known_answers;
+mod logging;
+mod params;
+mod parse;
+mod picture;
+mod player;
+pub mod puzzle_page;
+mod recorder;
+mod regions;
+mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+mod solution;
+
+pub use answer::*;
+pub use direction::*;
+pub use examples::*;
+pub use grid::*;
+pub use guesses::*;
+pub use input::*;
+pub use known_answers::*;
+pub use logging::*;
+pub use params::*;
+pub use parse::*;
+pub use picture::*;
+pub use player::*;
+pub use recorder::*;
+pub use regions::*;
+pub use rng::*;
+pub use solution::*;
 
 pub fn start_day(day: &str) {
     println!("Advent of Code 2024 - Day {:0>2}", day);
@@ -17,6 +56,35 @@ mod tests {
     fn it_works() {
         start_day("00");
     }
+
+    #[test]
+    fn wraps_into_bounds() {
+        let robot = Coord { x: 2, y: 4 } + Delta { x: -25, y: 17 } * 3;
+        assert_eq!(robot.rem_euclid((11, 7)), Coord { x: 4, y: 6 });
+    }
+
+    #[test]
+    fn measures_distances() {
+        let (a, b) = (Coord::from((1, 5)), Coord::from((4, 1)));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+    }
+
+    #[test]
+    fn normalizes_steps() {
+        assert_eq!(Delta::from((4, -6)).gcd_normalized(), Delta::from((2, -3)));
+        assert_eq!(Delta::from((0, -6)).gcd_normalized(), Delta::from((0, -1)));
+        assert_eq!(Delta::from((0, 0)).gcd_normalized(), Delta::from((0, 0)));
+        assert_eq!(Delta::from((4, -6)).signum(), Delta::from((1, -1)));
+    }
+
+    #[test]
+    fn rotates_like_directions() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.to_delta().rotate_right(), dir.turn_right().to_delta());
+            assert_eq!(dir.to_delta().rotate_left(), dir.turn_left().to_delta());
+        }
+    }
 }
 
 // My Utilities
@@ -34,27 +102,134 @@ pub struct Coord {
 
 // impl Eq for Coord {}
 
-impl<T> Index<Coord> for Vec<Vec<T>> {
-    type Output = T;
+impl Coord {
+    /// Wraps the coordinate into a `(width, height)` box, as if the box were tiled forever.
+    pub fn rem_euclid(self, (width, height): (usize, usize)) -> Self {
+        Self {
+            x: self.x.rem_euclid(width as isize),
+            y: self.y.rem_euclid(height as isize),
+        }
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        (self - other).manhattan()
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        (self - other).chebyshev()
+    }
+}
+
+impl From<(isize, isize)> for Coord {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
 
-    fn index(&self, Coord { x, y }: Coord) -> &Self::Output {
-        let x: usize = x.try_into().unwrap();
-        let y: usize = y.try_into().unwrap();
-        &self[y][x]
+impl From<Coord> for (isize, isize) {
+    fn from(Coord { x, y }: Coord) -> Self {
+        (x, y)
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Delta {
     pub x: isize,
     pub y: isize,
 }
 
 impl Delta {
-    pub fn pos(self) -> Self {
-        match self.x.is_positive() {
-            true => self,
-            false => -self,
+    /// Steps taken moving along the grid lines.
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Steps taken when diagonal moves are allowed.
+    pub fn chebyshev(self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// The smallest whole step in the same direction, e.g. (4, -6) becomes (2, -3).
+    pub fn gcd_normalized(self) -> Self {
+        fn gcd(a: usize, b: usize) -> usize {
+            match b {
+                0 => a,
+                _ => gcd(b, a % b),
+            }
+        }
+
+        match gcd(self.x.unsigned_abs(), self.y.unsigned_abs()) {
+            0 => self,
+            divisor => Self {
+                x: self.x / divisor as isize,
+                y: self.y / divisor as isize,
+            },
+        }
+    }
+
+    /// A quarter turn clockwise, as seen with y growing downwards.
+    pub fn rotate_right(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// A quarter turn anticlockwise, as seen with y growing downwards.
+    pub fn rotate_left(self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+impl From<(isize, isize)> for Delta {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Delta> for (isize, isize) {
+    fn from(Delta { x, y }: Delta) -> Self {
+        (x, y)
+    }
+}
+
+impl Add<Self> for Delta {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub<Self> for Delta {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// Scales each axis separately.
+impl Mul<Self> for Delta {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
         }
     }
 }
@@ -111,22 +286,14 @@ impl Sub<Delta> for Coord {
     }
 }
 
-pub fn debug_print_grid(points: impl Iterator<Item = Coord>, size: (usize, usize)) -> () {
-    let point_set: HashSet<Coord> = HashSet::from_iter(points);
-    for y in 0..size.1 {
-        for x in 0..size.0 {
-            print!(
-                "{}",
-                if point_set.contains(&Coord {
-                    x: x.try_into().unwrap(),
-                    y: y.try_into().unwrap()
-                }) {
-                    '#'
-                } else {
-                    '.'
-                }
-            );
+/// Scales the position away from the origin.
+impl Mul<isize> for Coord {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
         }
-        println!();
     }
 }
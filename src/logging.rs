@@ -0,0 +1,228 @@
+//! Tracing for the days that stays in the code: `debug!` and `trace!` print to stderr, so they
+//! never mix with the answers, and only for the days and level asked for with `-v`/`-vv`,
+//! `--log` or `AOC_LOG`. Nothing is formatted unless it will be printed, so a disabled `trace!`
+//! in a hot loop costs an atomic load.
+
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::{Arguments, Display};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+/// A log filter, as for `--log`, used when the command line doesn't give one.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// What a day is doing, a few lines per run.
+    Debug,
+    /// Every step, however many there are.
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "off" => Ok(Level::Off),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => bail!("Expected a log level (off, debug or trace), found {:?}", s),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Off => write!(f, "OFF"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+/// The level each day logs at, written as comma-separated directives: a level for every day
+/// (`debug`), a level for one day (`15=trace`), or a day alone for its debug output (`15`).
+/// Later directives win, so `trace,21=off` traces everything but day 21.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    default: Level,
+    days: BTreeMap<u8, Level>,
+}
+
+impl LogFilter {
+    pub const OFF: Self = Self {
+        default: Level::Off,
+        days: BTreeMap::new(),
+    };
+
+    /// Turns the days the filter logs up to at least `level`, as `-v` does, so `--log 15 -vv`
+    /// traces day 15 alone. A filter that logs nothing, like an empty one or `21=off`, turns up
+    /// every day it doesn't turn off.
+    pub fn at_least(mut self, level: Level) -> Self {
+        if self.max() == Level::Off {
+            self.default = level;
+        }
+        for day_level in self
+            .days
+            .values_mut()
+            .chain([&mut self.default])
+            .filter(|day_level| **day_level != Level::Off)
+        {
+            *day_level = (*day_level).max(level);
+        }
+        self
+    }
+
+    fn level_for(&self, day: Option<u8>) -> Level {
+        day.and_then(|day| self.days.get(&day).copied())
+            .unwrap_or(self.default)
+    }
+
+    fn max(&self) -> Level {
+        self.days.values().copied().fold(self.default, Level::max)
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut filter = Self::OFF;
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((day, level)) => {
+                    let day = day.trim().parse().with_context(|| {
+                        format!("Expected a day before the = in {:?}", directive)
+                    })?;
+                    filter.days.insert(day, level.trim().parse()?);
+                }
+                None => match directive.parse::<u8>() {
+                    Result::Ok(day) => {
+                        filter.days.insert(day, Level::Debug);
+                    }
+                    Err(_) => {
+                        filter.default = directive.parse()?;
+                        filter.days.clear();
+                    }
+                },
+            }
+        }
+        Ok(filter)
+    }
+}
+
+/// Set until the filter is first needed, when it's read from `AOC_LOG` unless one was set.
+const UNSET: u8 = u8::MAX;
+/// The loudest level any day logs at, so the common case is decided without the lock.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(UNSET);
+static FILTER: RwLock<LogFilter> = RwLock::new(LogFilter::OFF);
+
+pub fn set_log_filter(filter: LogFilter) {
+    let max = filter.max();
+    *FILTER.write().unwrap() = filter;
+    MAX_LEVEL.store(max as u8, Ordering::Relaxed);
+}
+
+/// The filter in `AOC_LOG`, or nothing logged if it isn't set.
+pub fn log_filter_from_env() -> Result<LogFilter> {
+    match std::env::var(LOG_ENV) {
+        Result::Ok(spec) => spec.parse().with_context(|| format!("In ${}", LOG_ENV)),
+        Err(_) => Ok(LogFilter::OFF),
+    }
+}
+
+/// The day a module belongs to, from its `dayNN` segment.
+fn day_of(module: &str) -> Option<u8> {
+    module
+        .split("::")
+        .find_map(|segment| segment.strip_prefix("day")?.parse().ok())
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn log_enabled(level: Level, module: &str) -> bool {
+    let mut max = MAX_LEVEL.load(Ordering::Relaxed);
+    if max == UNSET {
+        let filter = log_filter_from_env().unwrap_or_else(|error| {
+            eprintln!("Not logging: {:#}", error);
+            LogFilter::OFF
+        });
+        set_log_filter(filter);
+        max = MAX_LEVEL.load(Ordering::Relaxed);
+    }
+    level as u8 <= max && level <= FILTER.read().unwrap().level_for(day_of(module))
+}
+
+#[doc(hidden)]
+#[cold]
+pub fn write_log(level: Level, module: &str, message: Arguments) {
+    match day_of(module) {
+        Some(day) => eprintln!("[day{:02} {}] {}", day, level, message),
+        None => eprintln!("[{} {}] {}", module, level, message),
+    }
+}
+
+/// Logs at `level` if the calling day's filter allows it, formatting nothing otherwise.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log_enabled($level, module_path!()) {
+            $crate::write_log($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Logs what a day is doing, shown from `-v`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Debug, $($arg)+) };
+}
+
+/// Logs every step, shown from `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_by_day_and_level() {
+        let filter: LogFilter = "debug, 15=trace, 21=off".parse().unwrap();
+        assert_eq!(filter.level_for(Some(15)), Level::Trace);
+        assert_eq!(filter.level_for(Some(21)), Level::Off);
+        assert_eq!(filter.level_for(Some(7)), Level::Debug);
+        assert_eq!(filter.level_for(None), Level::Debug);
+        assert_eq!(filter.max(), Level::Trace);
+
+        let filter: LogFilter = "14".parse().unwrap();
+        assert_eq!(filter.level_for(Some(14)), Level::Debug);
+        assert_eq!(filter.level_for(Some(15)), Level::Off);
+        let filter = filter.at_least(Level::Trace);
+        assert_eq!(filter.level_for(Some(14)), Level::Trace);
+        assert_eq!(filter.level_for(Some(15)), Level::Off);
+        assert_eq!(LogFilter::OFF.at_least(Level::Debug).max(), Level::Debug);
+
+        // `--log 21=off -v` is everything but day 21
+        let filter = "21=off"
+            .parse::<LogFilter>()
+            .unwrap()
+            .at_least(Level::Debug);
+        assert_eq!(filter.level_for(Some(21)), Level::Off);
+        assert_eq!(filter.level_for(Some(7)), Level::Debug);
+        assert_eq!(filter.level_for(None), Level::Debug);
+
+        assert_eq!("".parse::<LogFilter>().unwrap(), LogFilter::OFF);
+        assert!("loud".parse::<LogFilter>().is_err());
+        assert!("x=debug".parse::<LogFilter>().is_err());
+        assert_eq!(day_of("adv_code_2024::days::day09::tests"), Some(9));
+        assert_eq!(day_of("adv_code_2024::runner"), None);
+    }
+}
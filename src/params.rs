@@ -0,0 +1,136 @@
+use crate::Part;
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A puzzle constant a day reads instead of hard-coding, like day 11's number of blinks.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The puzzle's value.
+    pub default: &'static str,
+    /// Where part 2 of the puzzle uses a different value, like day 11's 75 blinks.
+    pub part2_default: Option<&'static str>,
+}
+
+impl Param {
+    fn default_for(&self, part: Part) -> &'static str {
+        match part {
+            Part::Two => self.part2_default.unwrap_or(self.default),
+            Part::One => self.default,
+        }
+    }
+}
+
+/// The value of every parameter a day declares, for one part. Overrides (from `--param` or an
+/// example's header) replace the defaults for both parts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<&'static str, String>,
+}
+
+impl Params {
+    pub fn resolve(
+        declared: &'static [Param],
+        part: Part,
+        overrides: &BTreeMap<String, String>,
+    ) -> Result<Self> {
+        for name in overrides.keys() {
+            ensure!(
+                declared.iter().any(|param| param.name == name),
+                "There's no parameter called {:?}; the parameters are {:?}",
+                name,
+                declared.iter().map(|param| param.name).collect::<Vec<_>>()
+            );
+        }
+
+        let values = declared
+            .iter()
+            .map(|param| {
+                let value = match overrides.get(param.name) {
+                    Some(value) => value.clone(),
+                    None => param.default_for(part).to_string(),
+                };
+                (param.name, value)
+            })
+            .collect();
+        Ok(Self { values })
+    }
+
+    /// The parameter's value as whatever type the day wants it in.
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self
+            .values
+            .get(name)
+            .with_context(|| format!("Parameter {:?} isn't declared by this day", name))?;
+        value
+            .parse()
+            .map_err(|error| anyhow!("Parameter {}={}: {}", name, value, error))
+    }
+}
+
+/// Reads a `name=value` pair as given to `--param`.
+pub fn parse_override(text: &str) -> Result<(String, String)> {
+    let (name, value) = text
+        .split_once('=')
+        .with_context(|| format!("Expected name=value, found {:?}", text))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "blinks",
+            description: "How many times the stones change",
+            default: "25",
+            part2_default: Some("75"),
+        },
+        Param {
+            name: "width",
+            description: "Room width",
+            default: "101",
+            part2_default: None,
+        },
+    ];
+
+    #[test]
+    fn defaults_per_part_and_overrides_both() {
+        let none = BTreeMap::new();
+        let part1 = Params::resolve(PARAMS, Part::One, &none).unwrap();
+        let part2 = Params::resolve(PARAMS, Part::Two, &none).unwrap();
+        assert_eq!(part1.get::<usize>("blinks").unwrap(), 25);
+        assert_eq!(part2.get::<usize>("blinks").unwrap(), 75);
+        assert_eq!(part2.get::<isize>("width").unwrap(), 101);
+
+        let overrides = BTreeMap::from([parse_override("blinks = 40").unwrap()]);
+        for part in [Part::One, Part::Two] {
+            let params = Params::resolve(PARAMS, part, &overrides).unwrap();
+            assert_eq!(params.get::<u8>("blinks").unwrap(), 40);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_names_and_bad_values() {
+        let overrides = BTreeMap::from([parse_override("blnks=40").unwrap()]);
+        assert!(Params::resolve(PARAMS, Part::One, &overrides).is_err());
+
+        let overrides = BTreeMap::from([parse_override("blinks=lots").unwrap()]);
+        let params = Params::resolve(PARAMS, Part::One, &overrides).unwrap();
+        let error = params.get::<usize>("blinks").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Parameter blinks=lots: invalid digit found in string"
+        );
+        assert!(params.get::<usize>("seconds").is_err());
+        assert!(parse_override("blinks").is_err());
+    }
+}
@@ -0,0 +1,402 @@
+use crate::Grid;
+use std::fmt::{Debug, Display};
+use std::ops::{Deref, Range};
+use std::str::FromStr;
+
+/// Input that didn't match what a parser expected, pointing at where in the input it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by whoever knows where the text came from, see [`ParseError::name_input`].
+    pub input: Option<String>,
+    /// One-based.
+    pub line: usize,
+    /// One-based, in characters.
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    /// The whole line the error is on.
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// Names the input in any `ParseError` inside `error`, e.g. with the file it was read from.
+    pub fn name_input(mut error: anyhow::Error, name: &str) -> anyhow::Error {
+        if let Some(parse_error) = error.downcast_mut::<ParseError>() {
+            parse_error.input.get_or_insert_with(|| name.to_string());
+        }
+        error
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.input {
+            Some(input) => write!(f, "{}:{}:{}: ", input, self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?,
+        }
+        writeln!(f, "expected {}, found {}", self.expected, self.found)?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A piece of the input that remembers where it sits, so parsing it can report the line and
+/// column of anything unexpected. Derefs to `&str` for everything that can't fail.
+#[derive(Clone, Copy)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            text: source,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Wraps a slice of this span's text.
+    fn sub(&self, text: &'a str) -> Self {
+        Self {
+            source: self.source,
+            text,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.text.as_ptr() as usize - self.source.as_ptr() as usize
+    }
+
+    /// An error at the start of this span, reporting its text as what was found.
+    pub fn error(&self, expected: impl Display) -> ParseError {
+        let found = match self.text {
+            "" => "nothing".to_string(),
+            text => format!("{:?}", text.lines().next().unwrap_or(text)),
+        };
+        self.error_found(expected, found)
+    }
+
+    fn error_found(&self, expected: impl Display, found: String) -> ParseError {
+        let before = &self.source[..self.offset()];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let snippet = self.source[line_start..].lines().next().unwrap_or("");
+
+        ParseError {
+            input: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.to_string(),
+            found,
+            snippet: snippet.to_string(),
+        }
+    }
+
+    /// An error just past the end of this span, for when more was expected.
+    pub fn error_after(&self, expected: impl Display) -> ParseError {
+        self.sub(&self.text[self.text.len()..]).error(expected)
+    }
+
+    /// The part of this span at `range`, a byte range within its text like a regex match's.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        self.sub(&self.text[range])
+    }
+
+    pub fn lines(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.lines().map(move |line| self.sub(line))
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item = Span<'a>> {
+        self.text.split(separator).map(move |part| self.sub(part))
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.split_whitespace().map(move |part| self.sub(part))
+    }
+
+    /// Each character along with the one-character span it sits in.
+    pub fn chars(self) -> impl Iterator<Item = (char, Span<'a>)> {
+        self.text
+            .char_indices()
+            .map(move |(i, chr)| (chr, self.sub(&self.text[i..i + chr.len_utf8()])))
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(Self, Self), ParseError> {
+        match self.text.split_once(separator) {
+            Some((before, after)) => Ok((self.sub(before), self.sub(after))),
+            None => Err(self.error(format!("{:?}", separator))),
+        }
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("{:?}", prefix))),
+        }
+    }
+
+    /// Parses the whole span with `FromStr`, naming the target type if it fails.
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| {
+            let name = std::any::type_name::<T>();
+            self.error(name.rsplit("::").next().unwrap_or(name))
+        })
+    }
+}
+
+impl Deref for Span<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.text
+    }
+}
+
+impl Debug for Span<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.text)
+    }
+}
+
+/// Anything that turns a span into a `T`. Parsers are plain functions and closures, so they
+/// combine by passing one to another, e.g. `lines(pair("|", int, int))` for lines like `47|53`.
+pub trait Parser<'a, T>: Fn(Span<'a>) -> Result<T, ParseError> {}
+
+impl<'a, T, F: Fn(Span<'a>) -> Result<T, ParseError>> Parser<'a, T> for F {}
+
+/// What separates sections of an input, like rules from updates.
+pub const BLANK_LINE: &str = "\n\n";
+
+/// Runs a parser over a whole input.
+pub fn parse_all<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    parser(Span::new(input))
+}
+
+/// An integer of whatever type is wanted, signed or not, with an optional leading `+` or `-`.
+pub fn int<T: FromStr>(span: Span) -> Result<T, ParseError> {
+    span.parse()
+}
+
+/// The span's text as it is.
+pub fn string(span: Span) -> Result<String, ParseError> {
+    Ok(span.to_string())
+}
+
+/// Converts what `parser` found with `f`.
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |span| parser(span).map(&f)
+}
+
+/// Strips a fixed `prefix` and parses what follows, e.g. `prefixed("Register A: ", int)`.
+pub fn prefixed<'a, T>(prefix: &'static str, parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |span: Span<'a>| parser(span.strip_prefix(prefix)?)
+}
+
+/// Two things either side of the first `separator`.
+pub fn pair<'a, A, B>(
+    separator: &'static str,
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |span: Span<'a>| {
+        let (a, b) = span.split_once(separator)?;
+        Ok((first(a)?, second(b)?))
+    }
+}
+
+/// Items between each `separator`, e.g. `list(",", int)` for `75,47,61`.
+pub fn list<'a, T>(separator: &'static str, item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |span: Span<'a>| span.split(separator).map(&item).collect()
+}
+
+/// Items separated by any run of whitespace.
+pub fn words<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |span: Span<'a>| span.split_whitespace().map(&item).collect()
+}
+
+/// One item per line.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |span: Span<'a>| span.lines().map(&item).collect()
+}
+
+/// One item per section, with sections separated by blank lines.
+pub fn sections<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |span: Span<'a>| span.trim().split(BLANK_LINE).map(&item).collect()
+}
+
+/// A grid with one cell per character, where `cell` gets each character's span for errors.
+pub fn grid<'a, T>(
+    cell: impl Fn(char, Span<'a>) -> Result<T, ParseError>,
+) -> impl Parser<'a, Grid<T>> {
+    move |span: Span<'a>| {
+        let span = span.trim();
+        let width = span.lines().next().map_or(0, |line| line.chars().count());
+        let rows = span
+            .lines()
+            .map(|line| {
+                let found = line.chars().count();
+                if found != width {
+                    let found = format!("{} cells", found);
+                    return Err(line.error_found(format!("{} cells", width), found));
+                }
+                line.chars().map(|(chr, at)| cell(chr, at)).collect()
+            })
+            .collect::<Result<Vec<Vec<T>>, _>>()?;
+        Ok(Grid::from_rows(rows).expect("rows were checked to be the same width"))
+    }
+}
+
+/// Named fields like `Register A: 729` lines or `p=0,4 v=3,-3`, looked up by key.
+#[derive(Debug, Clone)]
+pub struct Record<'a> {
+    span: Span<'a>,
+    fields: Vec<(Span<'a>, Span<'a>)>,
+}
+
+impl<'a> Record<'a> {
+    /// Parses the value of the first field called `key`.
+    pub fn field<T>(&self, key: &str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+        let (_, value) = self
+            .fields
+            .iter()
+            .find(|(name, _)| name.as_str() == key)
+            .ok_or_else(|| self.span.error_after(format!("a {:?} field", key)))?;
+        parser(*value)
+    }
+}
+
+/// Fields separated by `field_separator`, each a key and value either side of `key_separator`.
+/// Empty fields are skipped, so `record("\n", ": ")` takes a block of `Key: value` lines.
+pub fn record<'a>(
+    field_separator: &'static str,
+    key_separator: &'static str,
+) -> impl Parser<'a, Record<'a>> {
+    move |span: Span<'a>| {
+        let span = span.trim();
+        let fields = span
+            .split(field_separator)
+            .filter(|field| !field.trim().is_empty())
+            .map(|field| field.trim().split_once(key_separator))
+            .collect::<Result<_, _>>()?;
+        Ok(Record { span, fields })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "\
+190: 10 19
+3267 81 40 27
+";
+
+    #[test]
+    fn points_at_the_problem() {
+        let source = Span::new(TEST);
+        let error = source
+            .lines()
+            .map(|line| line.split_once(": "))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "\": \"");
+        assert_eq!(error.found, "\"3267 81 40 27\"");
+        assert_eq!(
+            error.to_string(),
+            "\
+line 2, column 1: expected \": \", found \"3267 81 40 27\"
+  |
+2 | 3267 81 40 27
+  | ^"
+        );
+    }
+
+    #[test]
+    fn names_the_type_and_input() {
+        let line = Span::new("190: 10 1x9").split_once(": ").unwrap().1;
+        let error = line
+            .split(" ")
+            .map(|number| number.parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(error.column, 9);
+        assert_eq!(error.expected, "usize");
+
+        let error = ParseError::name_input(error.into(), "input/07.txt");
+        assert!(error
+            .to_string()
+            .starts_with("input/07.txt:1:9: expected usize, found \"1x9\""));
+    }
+
+    #[test]
+    fn reports_running_out_of_input() {
+        let error = Span::new("Register A: ")
+            .strip_prefix("Register A: ")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (13, "nothing"));
+    }
+
+    #[test]
+    fn combines_into_a_whole_format() {
+        let input = "47|53\n97|-13\n\n75,47,61\n97,61\n";
+        let (rules, updates): (Vec<(u8, i64)>, Vec<Vec<u32>>) = parse_all(
+            input,
+            pair(
+                BLANK_LINE,
+                lines(pair("|", int, int)),
+                lines(list(",", int)),
+            ),
+        )
+        .unwrap();
+        assert_eq!(rules, [(47, 53), (97, -13)]);
+        assert_eq!(updates, [vec![75, 47, 61], vec![97, 61]]);
+
+        let error = parse_all(input, lines(pair("|", int::<u8>, int::<u8>))).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (2, 4, "u8")
+        );
+    }
+
+    #[test]
+    fn looks_up_record_fields() {
+        let robot = parse_all("p=0,4 v=3,-3", record(" ", "=")).unwrap();
+        assert_eq!(robot.field("v", pair(",", int, int)), Ok((3, -3)));
+
+        let error = robot.field("q", int::<i32>).unwrap_err();
+        assert_eq!(error.expected, "a \"q\" field");
+        assert_eq!(error.column, 13);
+    }
+
+    #[test]
+    fn reads_grids_and_rejects_ragged_rows() {
+        let digit = |chr: char, at: Span| chr.to_digit(10).ok_or_else(|| at.error("a digit"));
+        let digits = parse_all("012\n345\n", grid(digit)).unwrap();
+        assert_eq!(digits[crate::Coord { x: 2, y: 1 }], 5);
+
+        let error = parse_all("012\n34x\n", grid(digit)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = parse_all("012\n34\n", grid(digit)).unwrap_err();
+        assert_eq!(
+            (error.expected.as_str(), error.found.as_str()),
+            ("3 cells", "2 cells")
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Draws positions as text, for tracing and for comparing in tests. Each layer gives its cells a
+//! character and maybe a colour, and a layer added later is drawn over the ones before it.
+
+use crate::*;
+use anyhow::*;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Write;
+
+struct Layer {
+    symbol: char,
+    color: Option<Rgb>,
+    cells: HashSet<Coord>,
+}
+
+/// A picture built up from layers, shown through `Display` or written with `write_to`.
+pub struct Picture {
+    layers: Vec<Layer>,
+    background: char,
+    size: Option<(usize, usize)>,
+    crop: Option<(Coord, Coord)>,
+    axes: bool,
+}
+
+impl Default for Picture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Picture {
+    pub fn new() -> Self {
+        Self {
+            layers: Vec::new(),
+            background: '.',
+            size: None,
+            crop: None,
+            axes: false,
+        }
+    }
+
+    pub fn layer(self, symbol: char, cells: impl IntoIterator<Item = Coord>) -> Self {
+        self.push_layer(symbol, None, cells)
+    }
+
+    /// A layer whose character is coloured with ANSI escapes.
+    pub fn colored_layer(
+        self,
+        symbol: char,
+        color: Rgb,
+        cells: impl IntoIterator<Item = Coord>,
+    ) -> Self {
+        self.push_layer(symbol, Some(color), cells)
+    }
+
+    fn push_layer(
+        mut self,
+        symbol: char,
+        color: Option<Rgb>,
+        cells: impl IntoIterator<Item = Coord>,
+    ) -> Self {
+        self.layers.push(Layer {
+            symbol,
+            color,
+            cells: cells.into_iter().collect(),
+        });
+        self
+    }
+
+    /// The character for cells in no layer, `.` unless changed.
+    pub fn background(mut self, symbol: char) -> Self {
+        self.background = symbol;
+        self
+    }
+
+    /// Shows a `width` by `height` area from (0, 0). Without it, the picture covers every
+    /// layer's cells and no more.
+    pub fn size(mut self, width: usize, height: usize) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Only shows the cells between these corners, inclusive.
+    pub fn crop(mut self, top_left: Coord, bottom_right: Coord) -> Self {
+        self.crop = Some((top_left, bottom_right));
+        self
+    }
+
+    /// Labels the rows down the left and the columns across the top, reading each column's
+    /// number downwards.
+    pub fn axes(mut self) -> Self {
+        self.axes = true;
+        self
+    }
+
+    /// The inclusive corners of the area shown, if there's anything to show.
+    fn bounds(&self) -> Option<(Coord, Coord)> {
+        let (mut min, mut max) = match self.size {
+            Some((0, _) | (_, 0)) => return None,
+            Some((width, height)) => (
+                Coord { x: 0, y: 0 },
+                Coord {
+                    x: width as isize - 1,
+                    y: height as isize - 1,
+                },
+            ),
+            None => {
+                let mut cells = self.layers.iter().flat_map(|layer| &layer.cells);
+                let first = *cells.next()?;
+                cells.fold((first, first), |(min, max), cell| {
+                    (
+                        Coord {
+                            x: min.x.min(cell.x),
+                            y: min.y.min(cell.y),
+                        },
+                        Coord {
+                            x: max.x.max(cell.x),
+                            y: max.y.max(cell.y),
+                        },
+                    )
+                })
+            }
+        };
+        if let Some((top_left, bottom_right)) = self.crop {
+            min = Coord {
+                x: min.x.max(top_left.x),
+                y: min.y.max(top_left.y),
+            };
+            max = Coord {
+                x: max.x.min(bottom_right.x),
+                y: max.y.min(bottom_right.y),
+            };
+        }
+        (min.x <= max.x && min.y <= max.y).then_some((min, max))
+    }
+
+    /// Writes the picture with a newline after every row, as printing it would.
+    pub fn write_to(&self, mut out: impl Write) -> Result<()> {
+        writeln!(out, "{}", self)?;
+        Ok(())
+    }
+}
+
+/// One line per row with no trailing newline, like `Grid`.
+impl Display for Picture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return std::fmt::Result::Ok(());
+        };
+
+        let mut lines = Vec::new();
+        let row_label_width = match self.axes {
+            true => (min.y..=max.y).map(|y| y.to_string().len()).max().unwrap(),
+            false => 0,
+        };
+        if self.axes {
+            let labels: Vec<String> = (min.x..=max.x).map(|x| x.to_string()).collect();
+            let label_width = labels.iter().map(String::len).max().unwrap();
+            let labels: Vec<Vec<char>> = labels
+                .iter()
+                .map(|label| format!("{:>1$}", label, label_width).chars().collect())
+                .collect();
+            for place in 0..label_width {
+                let digits: String = labels.iter().map(|label| label[place]).collect();
+                lines.push(format!("{:1$} {2}", "", row_label_width, digits));
+            }
+        }
+
+        for y in min.y..=max.y {
+            let mut line = match self.axes {
+                true => format!("{:>1$} ", y, row_label_width),
+                false => String::new(),
+            };
+            for x in min.x..=max.x {
+                let coord = Coord { x, y };
+                match self
+                    .layers
+                    .iter()
+                    .rev()
+                    .find(|layer| layer.cells.contains(&coord))
+                {
+                    Some(Layer {
+                        symbol,
+                        color: Some(Rgb(r, g, b)),
+                        ..
+                    }) => line += &format!("\x1b[38;2;{};{};{}m{}\x1b[0m", r, g, b, symbol),
+                    Some(Layer { symbol, .. }) => line.push(*symbol),
+                    None => line.push(self.background),
+                }
+            }
+            lines.push(line);
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_later_layers_over_earlier_ones() {
+        let picture = Picture::new()
+            .size(11, 2)
+            .layer('#', [Coord { x: 10, y: 0 }])
+            .layer('O', [Coord { x: 10, y: 0 }, Coord { x: 9, y: 1 }]);
+        assert_eq!(picture.to_string().lines().next(), Some("..........O"));
+
+        let picture = picture
+            .crop(Coord { x: 9, y: -5 }, Coord { x: 20, y: 1 })
+            .axes();
+        assert_eq!(picture.to_string(), "   1\n  90\n0 .O\n1 O.");
+    }
+
+    #[test]
+    fn fits_the_layers_and_colours_them() {
+        let Rgb(r, g, b) = Rgb::RED;
+        let picture = Picture::new()
+            .colored_layer('@', Rgb::RED, [Coord { x: -1, y: 0 }])
+            .layer('#', [Coord { x: 0, y: 1 }])
+            .background(' ');
+        let mut written = Vec::new();
+        picture.write_to(&mut written).unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            format!("\x1b[38;2;{r};{g};{b}m@\x1b[0m \n #\n")
+        );
+
+        assert_eq!(Picture::new().to_string(), "");
+        assert_eq!(Picture::new().size(3, 0).axes().to_string(), "");
+    }
+}
@@ -0,0 +1,363 @@
+//! Plays recorded frames in the terminal, to scrub through a simulation step by step. Frames are
+//! drawn in place with ANSI colours, two grid rows to a line of text.
+
+use crate::*;
+use anyhow::*;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// Frames held for playing, each cell an index into a shared palette to keep long runs small.
+#[derive(Debug, Default)]
+pub struct Reel {
+    width: usize,
+    height: usize,
+    palette: Vec<Rgb>,
+    /// The step each frame was captured at, and its cells row by row.
+    frames: Vec<(usize, Vec<u8>)>,
+}
+
+impl Reel {
+    pub fn push<T: Palette>(&mut self, step: usize, grid: &Grid<T>) -> Result<()> {
+        let pixels: Vec<Rgb> = grid.iter().map(|(_, cell)| cell.color()).collect();
+        self.push_pixels(step, grid.width(), grid.height(), &pixels)
+    }
+
+    pub(crate) fn push_pixels(
+        &mut self,
+        step: usize,
+        width: usize,
+        height: usize,
+        pixels: &[Rgb],
+    ) -> Result<()> {
+        if self.frames.is_empty() {
+            (self.width, self.height) = (width, height);
+        }
+        ensure!(
+            (width, height) == (self.width, self.height),
+            "Step {} is {}x{}, but the frames before it are {}x{}",
+            step,
+            width,
+            height,
+            self.width,
+            self.height
+        );
+
+        let mut cells = Vec::with_capacity(pixels.len());
+        for pixel in pixels {
+            let index = match self.palette.iter().position(|color| color == pixel) {
+                Some(index) => index,
+                None => {
+                    self.palette.push(*pixel);
+                    self.palette.len() - 1
+                }
+            };
+            ensure!(index < 256, "The player can only show 256 colours");
+            cells.push(index as u8);
+        }
+        self.frames.push((step, cells));
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    fn color(&self, frame: usize, x: usize, y: usize) -> Rgb {
+        self.palette[self.frames[frame].1[y * self.width + x] as usize]
+    }
+
+    /// The frame drawn with ANSI colours: each line of text is two rows of cells, the upper as
+    /// the foreground of a `▀` and the lower as its background.
+    fn draw(&self, frame: usize) -> String {
+        let mut text = String::new();
+        for y in (0..self.height).step_by(2) {
+            for x in 0..self.width {
+                let Rgb(r, g, b) = self.color(frame, x, y);
+                write!(text, "\x1b[38;2;{};{};{}m", r, g, b).unwrap();
+                match y + 1 < self.height {
+                    true => {
+                        let Rgb(r, g, b) = self.color(frame, x, y + 1);
+                        write!(text, "\x1b[48;2;{};{};{}m", r, g, b).unwrap();
+                    }
+                    false => text += "\x1b[49m",
+                }
+                text.push('▀');
+            }
+            text += "\x1b[0m\x1b[K\r\n";
+        }
+        text
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Char(char),
+    Left,
+    Right,
+    Enter,
+}
+
+/// Where playback is, apart from the terminal, so the controls can be tested.
+#[derive(Debug, PartialEq)]
+struct Playback {
+    frame: usize,
+    frames: usize,
+    playing: bool,
+    /// Frames per second while playing.
+    speed: f64,
+    /// Digits typed so far for a jump.
+    typed: String,
+    quit: bool,
+}
+
+impl Playback {
+    fn new(frames: usize) -> Self {
+        Self {
+            frame: 0,
+            frames,
+            playing: false,
+            speed: 10.0,
+            typed: String::new(),
+            quit: false,
+        }
+    }
+
+    fn handle(&mut self, key: Key, reel: &Reel) {
+        let last = self.frames.saturating_sub(1);
+        match key {
+            Key::Char(' ') => {
+                // Playing from the end starts again
+                if !self.playing && self.frame == last {
+                    self.frame = 0;
+                }
+                self.playing = !self.playing;
+            }
+            Key::Right | Key::Char('l') | Key::Char('.') => {
+                self.playing = false;
+                self.frame = (self.frame + 1).min(last);
+            }
+            Key::Left | Key::Char('h') | Key::Char(',') => {
+                self.playing = false;
+                self.frame = self.frame.saturating_sub(1);
+            }
+            Key::Char('+') | Key::Char('=') => self.speed = (self.speed * 2.0).min(960.0),
+            Key::Char('-') => self.speed = (self.speed / 2.0).max(0.25),
+            Key::Char(digit) if digit.is_ascii_digit() => self.typed.push(digit),
+            Key::Char('g') | Key::Enter => {
+                if let std::result::Result::Ok(step) = self.typed.parse::<usize>() {
+                    // The last frame captured at or before the step
+                    let frame = reel.frames.partition_point(|(at, _)| *at <= step);
+                    self.frame = frame.saturating_sub(1);
+                    self.playing = false;
+                }
+                self.typed.clear();
+            }
+            Key::Char('q') => self.quit = true,
+            Key::Char(_) => {}
+        }
+    }
+
+    /// Moves on a frame while playing, stopping at the end.
+    fn tick(&mut self) {
+        if self.playing {
+            self.frame += 1;
+            if self.frame + 1 >= self.frames {
+                self.frame = self.frames.saturating_sub(1);
+                self.playing = false;
+            }
+        }
+    }
+
+    fn status(&self, reel: &Reel) -> String {
+        format!(
+            "step {} (frame {}/{}) {} at {} fps{}\r\n\
+             [space] play/pause  [←/→] step  [N g] go to step N  [+/-] speed  [q] quit\x1b[K",
+            reel.frames[self.frame].0,
+            self.frame + 1,
+            self.frames,
+            match self.playing {
+                true => "playing",
+                false => "paused",
+            },
+            self.speed,
+            match self.typed.is_empty() {
+                true => String::new(),
+                false => format!("  go to step {}_", self.typed),
+            },
+        )
+    }
+}
+
+/// Keeps the terminal reading single key presses without echoing them, until dropped.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enable() -> Result<Self> {
+        stty(&["-icanon", "-echo", "min", "1"])?;
+        print!("\x1b[?1049h\x1b[?25l");
+        Ok(Self)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = std::io::stdout().flush();
+        let _ = stty(&["icanon", "echo"]);
+    }
+}
+
+fn stty(args: &[&str]) -> Result<()> {
+    let status = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::from(File::open("/dev/tty")?))
+        .status()
+        .context("Could not run stty to read single key presses")?;
+    ensure!(status.success(), "stty {} failed", args.join(" "));
+    Ok(())
+}
+
+/// Reads key presses from the terminal on another thread, so playback can go on between them.
+fn keys() -> Result<Receiver<Key>> {
+    let mut tty = File::open("/dev/tty").context("The player needs a terminal")?;
+    let (sender, receiver) = channel();
+    std::thread::spawn(move || {
+        let mut bytes = [0u8; 8];
+        while let std::result::Result::Ok(count) = tty.read(&mut bytes) {
+            let keys = match &bytes[..count] {
+                [] => break,
+                b"\x1b[C" => vec![Key::Right],
+                b"\x1b[D" => vec![Key::Left],
+                [b'\r'] | [b'\n'] => vec![Key::Enter],
+                other => String::from_utf8_lossy(other)
+                    .chars()
+                    .map(Key::Char)
+                    .collect(),
+            };
+            for key in keys {
+                if sender.send(key).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    Ok(receiver)
+}
+
+/// Plays the frames until `q` is pressed.
+pub fn play(reel: &Reel) -> Result<()> {
+    ensure!(!reel.is_empty(), "There are no frames to play");
+    let keys = keys()?;
+    let _terminal = RawTerminal::enable()?;
+    let mut playback = Playback::new(reel.len());
+    let mut stdout = std::io::stdout().lock();
+
+    while !playback.quit {
+        write!(
+            stdout,
+            "\x1b[H{}{}",
+            reel.draw(playback.frame),
+            playback.status(reel)
+        )?;
+        stdout.flush()?;
+
+        let wait = match playback.playing {
+            true => Duration::from_secs_f64(1.0 / playback.speed),
+            false => Duration::from_secs(3600),
+        };
+        match keys.recv_timeout(wait) {
+            std::result::Result::Ok(key) => playback.handle(key, reel),
+            Err(RecvTimeoutError::Timeout) => playback.tick(),
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reel() -> Reel {
+        let mut reel = Reel::default();
+        for step in [0, 10, 20, 30] {
+            let mut grid = Grid::new(2, 3, false);
+            grid[Coord::from(((step / 10 % 2) as isize, 1))] = true;
+            reel.push(step, &grid).unwrap();
+        }
+        reel
+    }
+
+    #[test]
+    fn steps_jumps_and_plays() {
+        let reel = reel();
+        let mut playback = Playback::new(reel.len());
+        let press = |playback: &mut Playback, keys: &str| {
+            for key in keys.chars() {
+                playback.handle(Key::Char(key), &reel);
+            }
+        };
+
+        press(&mut playback, "..");
+        assert_eq!(playback.frame, 2);
+        playback.handle(Key::Left, &reel);
+        assert_eq!(playback.frame, 1);
+
+        press(&mut playback, "25g");
+        assert_eq!((playback.frame, playback.typed.as_str()), (2, ""));
+        press(&mut playback, "99g");
+        assert_eq!(playback.frame, 3);
+
+        // Playing from the end starts over, and stops at the end again
+        press(&mut playback, " ");
+        assert_eq!((playback.frame, playback.playing), (0, true));
+        for _ in 0..5 {
+            playback.tick();
+        }
+        assert_eq!((playback.frame, playback.playing), (3, false));
+
+        press(&mut playback, "+-- q");
+        assert_eq!(playback.speed, 5.0);
+        assert!(playback.quit);
+        assert!(playback
+            .status(&reel)
+            .starts_with("step 0 (frame 1/4) playing at 5 fps"));
+    }
+
+    #[test]
+    fn draws_two_rows_per_line() {
+        let reel = reel();
+        let (b, w) = (Rgb::BLACK, Rgb::WHITE);
+        assert_eq!(reel.palette, [b, w]);
+
+        let cell = |Rgb(r, g, b): Rgb, Rgb(r2, g2, b2): Rgb| {
+            format!("\x1b[38;2;{r};{g};{b}m\x1b[48;2;{r2};{g2};{b2}m▀")
+        };
+        let last = |Rgb(r, g, b): Rgb| format!("\x1b[38;2;{r};{g};{b}m\x1b[49m▀");
+        let end = "\x1b[0m\x1b[K\r\n";
+        assert_eq!(
+            reel.draw(1),
+            [
+                cell(b, b),
+                cell(b, w),
+                end.to_string(),
+                last(b),
+                last(b),
+                end.to_string()
+            ]
+            .concat()
+        );
+
+        let mut wrong_size = Reel::default();
+        wrong_size.push(0, &Grid::new(2, 2, false)).unwrap();
+        assert!(wrong_size.push(1, &Grid::new(3, 2, false)).is_err());
+    }
+}
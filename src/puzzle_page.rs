@@ -0,0 +1,276 @@
+//! Pulls the examples out of a puzzle page saved from the browser, so they don't have to be
+//! copied by hand (and get a trailing newline or some indentation wrong on the way).
+
+use crate::{examples_dir, load_examples, Example, Part};
+use anyhow::*;
+use regex::Regex;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// A `<pre><code>` block from the puzzle text, with the answers it looks like it should give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The part of the puzzle whose text the block is in.
+    pub part: Part,
+    pub text: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct PuzzlePage {
+    /// From the page's `--- Day N: ... ---` heading.
+    pub day: Option<u8>,
+    pub blocks: Vec<CodeBlock>,
+}
+
+/// An `<article>`'s code blocks and emphasised values, each with where it is in the article.
+struct Article<'a> {
+    blocks: Vec<(Range<usize>, String)>,
+    emphasised: Vec<(usize, &'a str)>,
+}
+
+impl<'a> Article<'a> {
+    fn parse(html: &'a str) -> Self {
+        let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+        let emphasised = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+
+        let blocks: Vec<_> = pre
+            .captures_iter(html)
+            .map(|captures| {
+                let text = text_content(&captures[1]);
+                // The puzzle's inputs always end in a newline, even if the page's blocks don't
+                let text = match text.ends_with('\n') {
+                    true => text,
+                    false => text + "\n",
+                };
+                (captures.get(0).unwrap().range(), text)
+            })
+            .collect();
+        let emphasised = emphasised
+            .captures_iter(html)
+            .map(|captures| captures.get(1).unwrap())
+            .filter(|value| {
+                !blocks
+                    .iter()
+                    .any(|(range, _)| range.contains(&value.start()))
+            })
+            .map(|value| (value.start(), value.as_str()))
+            .collect();
+        Self { blocks, emphasised }
+    }
+
+    /// The answer for the block at `index`: the last value emphasised between it and the next
+    /// block, as the answer usually follows its example, or else the article's last one.
+    fn answer_for(&self, index: usize) -> Option<String> {
+        let start = self.blocks[index].0.end;
+        let end = self
+            .blocks
+            .get(index + 1)
+            .map_or(usize::MAX, |(range, _)| range.start);
+        self.emphasised
+            .iter()
+            .rev()
+            .find(|(position, _)| (start..end).contains(position))
+            .or(self.emphasised.last())
+            .map(|(_, value)| text_content(value))
+    }
+}
+
+impl PuzzlePage {
+    pub fn parse(html: &str) -> Self {
+        let heading = Regex::new(r"--- Day (\d+):").unwrap();
+        let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+
+        let day = heading
+            .captures(html)
+            .and_then(|captures| captures[1].parse().ok());
+        let articles: Vec<_> = article
+            .captures_iter(html)
+            .map(|captures| Article::parse(captures.get(1).unwrap().as_str()))
+            .collect();
+
+        let mut blocks = Vec::new();
+        for (part, article) in [Part::One, Part::Two].into_iter().zip(&articles) {
+            for (index, (_, text)) in article.blocks.iter().enumerate() {
+                let answer = article.answer_for(index);
+                let block = match part {
+                    Part::One => CodeBlock {
+                        part,
+                        text: text.clone(),
+                        part1: answer,
+                        // Part 2 usually reuses part 1's example rather than giving its own
+                        part2: articles
+                            .get(1)
+                            .filter(|part2| part2.blocks.is_empty())
+                            .and_then(|part2| part2.emphasised.last())
+                            .map(|(_, value)| text_content(value)),
+                    },
+                    Part::Two => CodeBlock {
+                        part,
+                        text: text.clone(),
+                        part1: None,
+                        part2: answer,
+                    },
+                };
+                blocks.push(block);
+            }
+        }
+        Self { day, blocks }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let html = fs::read_to_string(path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        Ok(Self::parse(&html))
+    }
+}
+
+/// The text of some HTML, without its tags and with its entities decoded.
+fn text_content(html: &str) -> String {
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    let entity = Regex::new(r"&(#[0-9]+|#x[0-9a-fA-F]+|[a-z]+);").unwrap();
+
+    let text = tag.replace_all(html, "");
+    entity
+        .replace_all(&text, |captures: &regex::Captures| {
+            let name = &captures[1];
+            let decoded = match name {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => match name.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => name.strip_prefix('#').and_then(|code| code.parse().ok()),
+                }
+                .and_then(char::from_u32),
+            };
+            match decoded {
+                Some(decoded) => decoded.to_string(),
+                None => captures[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+impl CodeBlock {
+    /// The block as an example file, with its guessed answers in the header.
+    pub fn example_file(&self) -> String {
+        let mut file = String::from("# Answers guessed from the puzzle page; check them\n");
+        for (key, answer) in [("part1", &self.part1), ("part2", &self.part2)] {
+            match answer {
+                Some(answer) => {
+                    let value = toml::Value::String(answer.clone());
+                    file += &format!("{} = {}\n", key, value);
+                }
+                None => file += &format!("# {} = \"\"\n", key),
+            }
+        }
+        file + "---\n" + &self.text
+    }
+
+    /// The block as a Rust string constant, for pasting into a test.
+    pub fn rust_const(&self) -> String {
+        let escaped = self.text.replace('\\', "\\\\").replace('"', "\\\"");
+        // A `\` at the end of the first line would also swallow the input's leading whitespace
+        match escaped.starts_with(char::is_whitespace) {
+            true => format!("const TEST: &str = \"{}\";", escaped),
+            false => format!("const TEST: &str = \"\\\n{}\";", escaped),
+        }
+    }
+}
+
+/// Saves the block as the day's next numbered example, unless one already has the same input.
+pub fn save_example(day: u8, block: &CodeBlock) -> Result<PathBuf> {
+    let examples = load_examples(day)?;
+    if let Some(existing) = examples.iter().find(|example| example.input == block.text) {
+        bail!(
+            "That example is already saved as {}",
+            examples_dir(day)
+                .join(format!("{}.txt", existing.name))
+                .display()
+        );
+    }
+
+    let number = examples
+        .iter()
+        .filter_map(|example| example.name.parse::<usize>().ok())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let path = examples_dir(day).join(format!("{}.txt", number));
+    let file = block.example_file();
+    Example::parse(&number.to_string(), &file).context("The example doesn't read back")?;
+    fs::create_dir_all(examples_dir(day))?;
+    fs::write(&path, file)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><head><title>Day 7 - Advent of Code 2024</title></head><body>
+<main>
+<article class="day-desc"><h2>--- Day 7: Bridge Repair ---</h2>
+<p>For example:</p>
+<pre><code>190: 10 19
+3267: 81 40 27
+</code></pre>
+<p>The first example gives <code><em>190</em></code>. Another:</p>
+<pre><code>a &lt;<em>b</em>&gt; &amp; c</code></pre>
+<p>Their sum is <code><em>3749</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>12</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now the total is <code><em>11387</em></code>.</p>
+</article>
+</main></body></html>
+"#;
+
+    #[test]
+    fn finds_blocks_and_guesses_answers() {
+        let page = PuzzlePage::parse(PAGE);
+        assert_eq!(page.day, Some(7));
+        assert_eq!(
+            page.blocks,
+            [
+                CodeBlock {
+                    part: Part::One,
+                    text: "190: 10 19\n3267: 81 40 27\n".to_string(),
+                    part1: Some("190".to_string()),
+                    part2: Some("11387".to_string()),
+                },
+                CodeBlock {
+                    part: Part::One,
+                    text: "a <b> & c\n".to_string(),
+                    part1: Some("3749".to_string()),
+                    part2: Some("11387".to_string()),
+                },
+            ]
+        );
+
+        let example = Example::parse("1", &page.blocks[1].example_file()).unwrap();
+        assert_eq!(example.input, "a <b> & c\n");
+        assert_eq!(example.expected(Part::One), Some("3749"));
+    }
+
+    #[test]
+    fn writes_rust_constants() {
+        let block = |text: &str| CodeBlock {
+            part: Part::One,
+            text: text.to_string(),
+            part1: None,
+            part2: None,
+        };
+        assert_eq!(
+            block("\"a\"\\\nb\n").rust_const(),
+            "const TEST: &str = \"\\\n\\\"a\\\"\\\\\nb\n\";"
+        );
+        assert_eq!(block("  x\n").rust_const(), "const TEST: &str = \"  x\n\";");
+    }
+}
@@ -0,0 +1,439 @@
+//! Records a simulation's grid as pictures, one frame per step, to watch instead of reading text
+//! dumps. Days capture frames from a `Recorder`, which does nothing unless `run --record` asked
+//! for a recording, so the captures can stay in the code.
+
+use crate::*;
+use anyhow::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Rgb(0x0f, 0x0f, 0x23);
+    pub const WHITE: Self = Rgb(0xcc, 0xcc, 0xcc);
+    pub const GREEN: Self = Rgb(0x00, 0x99, 0x00);
+    pub const GOLD: Self = Rgb(0xff, 0xff, 0x66);
+    pub const GREY: Self = Rgb(0x66, 0x66, 0x66);
+    pub const RED: Self = Rgb(0xff, 0x44, 0x44);
+}
+
+/// How a cell type is drawn in recorded frames.
+pub trait Palette {
+    fn color(&self) -> Rgb;
+}
+
+impl Palette for bool {
+    fn color(&self) -> Rgb {
+        match self {
+            true => Rgb::WHITE,
+            false => Rgb::BLACK,
+        }
+    }
+}
+
+/// Empty cells are the background.
+impl<T: Palette> Palette for Option<T> {
+    fn color(&self) -> Rgb {
+        match self {
+            Some(cell) => cell.color(),
+            None => Rgb::BLACK,
+        }
+    }
+}
+
+impl Palette for Rgb {
+    fn color(&self) -> Rgb {
+        *self
+    }
+}
+
+/// The part of the grid to keep, as `x,y,width,height` in cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crop {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl FromStr for Crop {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let numbers = s
+            .split(',')
+            .map(|number| number.trim().parse())
+            .collect::<Result<Vec<usize>, _>>()
+            .with_context(|| format!("Expected x,y,width,height, found {:?}", s))?;
+        let [x, y, width, height] = numbers[..] else {
+            bail!("Expected x,y,width,height, found {:?}", s);
+        };
+        ensure!(width > 0 && height > 0, "The crop {:?} is empty", s);
+        Ok(Self {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+}
+
+/// Where a recording goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    /// A `.gif` is one animation; for `.png` or `.ppm`, each frame is written next to the path
+    /// with its number, like `day14-00001.png`.
+    File(PathBuf),
+    /// The frames are kept and played in the terminal once the recording finishes.
+    Player,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordOptions {
+    pub output: Output,
+    /// Keep one step in this many.
+    pub every: usize,
+    pub crop: Option<Crop>,
+    /// Pixels per cell along each side, in files.
+    pub scale: usize,
+    /// How long each frame of a GIF shows, in hundredths of a second.
+    pub delay: u16,
+}
+
+impl RecordOptions {
+    pub fn new(output: Output) -> Self {
+        Self {
+            output,
+            every: 1,
+            crop: None,
+            scale: 4,
+            delay: 5,
+        }
+    }
+}
+
+/// The recording `run --record` or `run --play` asked for, which the next `Recorder::start`
+/// picks up.
+static RECORDING: Mutex<Option<RecordOptions>> = Mutex::new(None);
+
+/// Sets the recording for the days run from now on, returning the one it replaces.
+pub fn set_recording(options: Option<RecordOptions>) -> Option<RecordOptions> {
+    std::mem::replace(&mut *RECORDING.lock().unwrap(), options)
+}
+
+/// A picture of one frame, already cropped and scaled.
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    fn draw<T: Palette>(grid: &Grid<T>, crop: Option<Crop>, scale: usize) -> Result<Self> {
+        let crop = crop.unwrap_or(Crop {
+            x: 0,
+            y: 0,
+            width: grid.width(),
+            height: grid.height(),
+        });
+        ensure!(
+            crop.x + crop.width <= grid.width() && crop.y + crop.height <= grid.height(),
+            "Can't crop {:?} from a {}x{} grid",
+            crop,
+            grid.width(),
+            grid.height()
+        );
+
+        let scale = scale.max(1);
+        let (width, height) = (crop.width * scale, crop.height * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let cell = Coord {
+                    x: (crop.x + x / scale) as isize,
+                    y: (crop.y + y / scale) as isize,
+                };
+                pixels.push(grid[cell].color());
+            }
+        }
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+            .collect()
+    }
+}
+
+/// Where frames go as they're captured. Files are written as they come, so a long run never
+/// holds them all in memory.
+enum Sink {
+    Ppm(PathBuf),
+    Png(PathBuf),
+    Gif(PathBuf, Option<gif::Encoder<BufWriter<File>>>),
+    Player(Reel),
+}
+
+/// `path` with a frame number added, like `day14-00001.png`.
+fn frame_path(path: &Path, frame: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}-{}.{}", stem, frame, extension))
+}
+
+pub struct Recorder {
+    options: Option<RecordOptions>,
+    sink: Sink,
+    steps: usize,
+    frames: usize,
+}
+
+impl Recorder {
+    /// A recorder for the recording `run --record` or `run --play` asked for, if any.
+    pub fn start() -> Result<Self> {
+        match RECORDING.lock().unwrap().clone() {
+            Some(options) => Self::new(options),
+            None => Ok(Self::off()),
+        }
+    }
+
+    /// A recorder that captures nothing.
+    pub fn off() -> Self {
+        Self {
+            options: None,
+            sink: Sink::Player(Reel::default()),
+            steps: 0,
+            frames: 0,
+        }
+    }
+
+    pub fn new(options: RecordOptions) -> Result<Self> {
+        ensure!(options.every > 0, "Can't keep one frame in every 0");
+        let sink = match &options.output {
+            Output::Player => Sink::Player(Reel::default()),
+            Output::File(path) => {
+                let sink = match path.extension().and_then(|extension| extension.to_str()) {
+                    Some("ppm") => Sink::Ppm(path.clone()),
+                    Some("png") => Sink::Png(path.clone()),
+                    Some("gif") => Sink::Gif(path.clone(), None),
+                    _ => bail!(
+                        "Can't record to {}; give a .gif, .png or .ppm path",
+                        path.display()
+                    ),
+                };
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                sink
+            }
+        };
+        Ok(Self {
+            options: Some(options),
+            sink,
+            steps: 0,
+            frames: 0,
+        })
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.options.is_some()
+    }
+
+    /// Captures a step. `grid` is only called for the steps that are kept.
+    pub fn capture<T: Palette>(&mut self, grid: impl FnOnce() -> Grid<T>) -> Result<()> {
+        let Some(options) = &self.options else {
+            return Ok(());
+        };
+        let step = self.steps;
+        self.steps += 1;
+        if !step.is_multiple_of(options.every) {
+            return Ok(());
+        }
+
+        self.frames += 1;
+        let number = format!("{:05}", self.frames);
+        match &mut self.sink {
+            Sink::Ppm(path) => {
+                let image = Image::draw(&grid(), options.crop, options.scale)?;
+                let mut file = BufWriter::new(File::create(frame_path(path, &number))?);
+                write!(file, "P6\n{} {}\n255\n", image.width, image.height)?;
+                file.write_all(&image.bytes())?;
+                file.flush()?;
+            }
+            Sink::Png(path) => {
+                let image = Image::draw(&grid(), options.crop, options.scale)?;
+                let file = BufWriter::new(File::create(frame_path(path, &number))?);
+                let mut encoder = png::Encoder::new(file, image.width as u32, image.height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                let mut writer = encoder.write_header()?;
+                writer.write_image_data(&image.bytes())?;
+                writer.finish()?;
+            }
+            Sink::Gif(path, encoder) => {
+                let image = Image::draw(&grid(), options.crop, options.scale)?;
+                let (width, height) = (u16::try_from(image.width)?, u16::try_from(image.height)?);
+                let encoder = match encoder {
+                    Some(encoder) => encoder,
+                    None => {
+                        let file = BufWriter::new(File::create(&*path)?);
+                        let mut new = gif::Encoder::new(file, width, height, &[])?;
+                        new.set_repeat(gif::Repeat::Infinite)?;
+                        encoder.insert(new)
+                    }
+                };
+                // Each frame gets its own palette, which is plenty for a day's few cell types
+                let mut palette: Vec<Rgb> = Vec::new();
+                let mut indices = Vec::with_capacity(image.pixels.len());
+                for pixel in &image.pixels {
+                    let index = match palette.iter().position(|color| color == pixel) {
+                        Some(index) => index,
+                        None => {
+                            palette.push(*pixel);
+                            palette.len() - 1
+                        }
+                    };
+                    ensure!(index < 256, "A GIF frame can only have 256 colours");
+                    indices.push(index as u8);
+                }
+                let frame = gif::Frame {
+                    width,
+                    height,
+                    delay: options.delay,
+                    palette: Some(
+                        palette
+                            .iter()
+                            .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+                            .collect(),
+                    ),
+                    buffer: indices.into(),
+                    ..gif::Frame::default()
+                };
+                encoder.write_frame(&frame)?;
+            }
+            Sink::Player(reel) => {
+                // A terminal cell is already as small as a picture gets
+                let image = Image::draw(&grid(), options.crop, 1)?;
+                reel.push_pixels(step, image.width, image.height, &image.pixels)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finishes the file and says where the frames went, or plays them.
+    pub fn finish(self) -> Result<()> {
+        if self.options.is_none() {
+            return Ok(());
+        }
+        let written = match self.sink {
+            Sink::Gif(path, encoder) => {
+                if let Some(encoder) = encoder {
+                    encoder.into_inner()?.flush()?;
+                }
+                path.display().to_string()
+            }
+            Sink::Ppm(path) | Sink::Png(path) => frame_path(&path, "*").display().to_string(),
+            Sink::Player(reel) => {
+                if !reel.is_empty() {
+                    play(&reel)?;
+                }
+                "the player".to_string()
+            }
+        };
+        println!(
+            "Recorded {} of {} steps to {}",
+            self.frames, self.steps, written
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn diagonal(step: usize) -> Grid<bool> {
+        let mut grid = Grid::new(4, 3, false);
+        grid[Coord::from(((step % 4) as isize, (step % 3) as isize))] = true;
+        grid
+    }
+
+    #[test]
+    fn skips_and_crops_frames() {
+        let dir = temp_dir("record-ppm");
+        let mut recorder = Recorder::new(RecordOptions {
+            every: 2,
+            crop: Some("0,0,2,2".parse().unwrap()),
+            scale: 1,
+            ..RecordOptions::new(Output::File(dir.join("walk.ppm")))
+        })
+        .unwrap();
+        for step in 0..5 {
+            recorder.capture(|| diagonal(step)).unwrap();
+        }
+        recorder.finish().unwrap();
+
+        let mut files: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            ["walk-00001.ppm", "walk-00002.ppm", "walk-00003.ppm"]
+        );
+
+        // Steps 0, 2 and 4 light (0, 0), (2, 2) and (0, 1); (2, 2) is outside the crop
+        let (b, w) = (Rgb::BLACK, Rgb::WHITE);
+        for (frame, pixels) in [(1, [w, b, b, b]), (2, [b, b, b, b]), (3, [b, b, w, b])] {
+            let file = fs::read(dir.join(format!("walk-{:05}.ppm", frame))).unwrap();
+            let pixels = pixels.map(|Rgb(r, g, b)| [r, g, b]).concat();
+            assert_eq!(file, [b"P6\n2 2\n255\n".as_slice(), &pixels].concat());
+        }
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!("1,2,3".parse::<Crop>().is_err());
+        assert!(Recorder::new(RecordOptions::new(Output::File("frames.bmp".into()))).is_err());
+        assert!(!Recorder::off().is_recording());
+    }
+
+    #[test]
+    fn writes_an_animated_gif() {
+        let dir = temp_dir("record-gif");
+        let path = dir.join("walk.gif");
+        let mut recorder = Recorder::new(RecordOptions::new(Output::File(path.clone()))).unwrap();
+        for step in 0..6 {
+            recorder.capture(|| diagonal(step)).unwrap();
+        }
+        recorder.finish().unwrap();
+
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(&path).unwrap())
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (16, 12));
+        let mut frames = 0;
+        while decoder.read_next_frame().unwrap().is_some() {
+            frames += 1;
+        }
+        assert_eq!(frames, 6);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use crate::*;
+use std::collections::VecDeque;
+
+/// A connected group of orthogonally adjacent cells holding equal values.
+#[derive(Debug, Clone)]
+pub struct Region {
+    /// In the order the flood fill reached them, starting from the top-left-most cell.
+    pub members: Vec<Coord>,
+    /// Edges between a member and a non-member (or the outside of the grid).
+    pub perimeter: usize,
+    /// Straight runs of fence, counted as corners since a closed outline has one per side.
+    pub sides: usize,
+    /// Inclusive top-left and bottom-right corners.
+    pub bounds: (Coord, Coord),
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn width(&self) -> usize {
+        (self.bounds.1.x - self.bounds.0.x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.bounds.1.y - self.bounds.0.y) as usize + 1
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Splits the grid into regions of equal neighbouring cells by flood fill, in linear time.
+    /// Returns each cell's index into the list of regions, along with the regions.
+    pub fn label_regions(&self) -> (Grid<usize>, Vec<Region>) {
+        let mut labels = Grid::new(self.width(), self.height(), usize::MAX);
+        let mut regions = Vec::new();
+
+        for start in self.coords() {
+            if labels[start] != usize::MAX {
+                continue;
+            }
+            let label = regions.len();
+            labels[start] = label;
+            let mut members = vec![];
+            let mut queue = VecDeque::from([start]);
+            while let Some(coord) = queue.pop_front() {
+                members.push(coord);
+                for neighbor in self.neighbors4(coord) {
+                    if labels[neighbor] == usize::MAX && self[neighbor] == self[start] {
+                        labels[neighbor] = label;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            regions.push(members);
+        }
+
+        let regions = regions
+            .into_iter()
+            .enumerate()
+            .map(|(label, members)| measure(&labels, label, members))
+            .collect();
+        (labels, regions)
+    }
+}
+
+fn measure(labels: &Grid<usize>, label: usize, members: Vec<Coord>) -> Region {
+    let inside = |coord: Coord| labels.get(coord) == Some(&label);
+
+    let mut perimeter = 0;
+    let mut sides = 0;
+    for &coord in &members {
+        for dir in Direction::CARDINAL {
+            if !inside(coord + dir.to_delta()) {
+                perimeter += 1;
+            }
+
+            // Look at the corner between this direction and the next one clockwise
+            let side = dir.turn_right();
+            let ahead = inside(coord + dir.to_delta());
+            let beside = inside(coord + side.to_delta());
+            let diagonal = inside(coord + dir.to_delta() + side.to_delta());
+            let outer_corner = !ahead && !beside;
+            let inner_corner = ahead && beside && !diagonal;
+            if outer_corner || inner_corner {
+                sides += 1;
+            }
+        }
+    }
+
+    let top_left = Coord {
+        x: members.iter().map(|coord| coord.x).min().unwrap(),
+        y: members.iter().map(|coord| coord.y).min().unwrap(),
+    };
+    let bottom_right = Coord {
+        x: members.iter().map(|coord| coord.x).max().unwrap(),
+        y: members.iter().map(|coord| coord.y).max().unwrap(),
+    };
+
+    Region {
+        members,
+        perimeter,
+        sides,
+        bounds: (top_left, bottom_right),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    #[test]
+    fn measures_each_region() {
+        let grid = Grid::from_reader(TEST.as_bytes(), |chr| chr).unwrap();
+        let (labels, regions) = grid.label_regions();
+
+        let summary: Vec<_> = regions
+            .iter()
+            .map(|region| {
+                let plant = grid[region.members[0]];
+                (plant, region.area(), region.perimeter, region.sides)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+
+        let c = &regions[labels[Coord { x: 2, y: 1 }]];
+        assert_eq!(c.bounds, (Coord { x: 2, y: 1 }, Coord { x: 3, y: 3 }));
+        assert_eq!((c.width(), c.height()), (2, 3));
+    }
+
+    #[test]
+    fn counts_sides_around_holes() {
+        let grid = Grid::from_reader("OOO\nOXO\nOOO\n".as_bytes(), |chr| chr).unwrap();
+        let (_, regions) = grid.label_regions();
+        assert_eq!(regions.len(), 2);
+        assert_eq!((regions[0].perimeter, regions[0].sides), (16, 8));
+    }
+}
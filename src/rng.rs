@@ -0,0 +1,77 @@
+//! Seeded randomness for generating puzzle inputs, so a slow or failing input can be made again
+//! from its seed.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64: tiny, fast, and random enough for test inputs. Not for anything that matters.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to but not including `bound`, which must not be 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Can't pick a number below 0");
+        // Multiplying rather than taking the remainder keeps small bounds close to even
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = (range.end() - range.start()) as u64 as usize + 1;
+        range.start() + self.below(span) as i64
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_from_a_seed_and_stays_in_range() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        assert_eq!(
+            (0..5).map(|_| a.next_u64()).collect::<Vec<_>>(),
+            (0..5).map(|_| b.next_u64()).collect::<Vec<_>>()
+        );
+        // The first output for seed 0, as published with SplitMix64
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+
+        let mut rng = Rng::new(1);
+        let rolls: Vec<i64> = (0..1000).map(|_| rng.range(-2..=2)).collect();
+        assert!(rolls.iter().all(|roll| (-2..=2).contains(roll)));
+        assert!((-2..=2).all(|side| rolls.contains(&side)));
+
+        let mut cards: Vec<usize> = (0..20).collect();
+        rng.shuffle(&mut cards);
+        assert_ne!(cards, (0..20).collect::<Vec<_>>());
+        cards.sort();
+        assert_eq!(cards, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::*;
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+pub fn input_path(day: u8) -> String {
+    format!("input/{:02}.txt", day)
+}
+
+/// Runs the selected parts against every example that lists an expected answer for them.
+pub fn check_examples(solution: &dyn AnySolution, parts: &[Part]) -> Result<()> {
+    count_checked_examples(solution, parts).map(|_| ())
+}
+
+/// Checks the examples like `check_examples`, returning how many answers it checked.
+fn count_checked_examples(solution: &dyn AnySolution, parts: &[Part]) -> Result<usize> {
+    let mut checked = 0;
+    for example in solution.examples()? {
+        let wanted: Vec<_> = parts
+            .iter()
+            .filter_map(|part| example.expected(*part).map(|expected| (*part, expected)))
+            .collect();
+        if wanted.is_empty() {
+            continue;
+        }
+
+        let path = examples_dir(solution.day()).join(format!("{}.txt", example.name));
+        let input = solution
+            .parse(&mut example.input.as_bytes())
+            .map_err(|error| ParseError::name_input(error, &path.display().to_string()))?;
+        for (part, expected) in wanted {
+            let params = Params::resolve(solution.params(), part, &example.params)
+                .with_context(|| format!("In {}", path.display()))?;
+            let answer = solution.solve(input.as_ref(), part, &params)?;
+            ensure!(
+                answer == expected,
+                "Day {:02} part {} example {}: expected {}, got {}",
+                solution.day(),
+                part,
+                example.name,
+                expected,
+                answer
+            );
+            checked += 1;
+        }
+    }
+    Ok(checked)
+}
+
+/// Checks the examples, then solves the selected parts against the given input and
+/// compares each result with the known answers. A failing example is reported but doesn't
+/// stop the real input from being solved; `cargo test` is where examples are enforced.
+/// `overrides` replace the day's parameters, on top of any an example input sets.
+pub fn run(
+    solution: &dyn AnySolution,
+    input: &InputSource,
+    overrides: &BTreeMap<String, String>,
+    parts: &[Part],
+    known: &KnownAnswers,
+) -> Result<()> {
+    start_day(&solution.day().to_string());
+
+    // Only the given input is recorded, not the examples checked first
+    let recording = set_recording(None);
+    let examples = count_checked_examples(solution, parts);
+    set_recording(recording);
+    match examples {
+        Result::Ok(0) => println!("Examples: no examples"),
+        Result::Ok(_) => println!("Examples: ok"),
+        Err(error) => println!("Examples: {:#}", error),
+    }
+
+    let text = input.read(solution)?;
+    let start = Instant::now();
+    let parsed = solution
+        .parse(&mut text.as_bytes())
+        .map_err(|error| ParseError::name_input(error, &input.to_string()))?;
+    println!("Parsing {} took {:?}.", input, start.elapsed());
+
+    let overrides = match input {
+        InputSource::Example(name) => {
+            let mut params = load_example(solution.day(), name)?.params;
+            params.extend(overrides.clone());
+            params
+        }
+        _ => overrides.clone(),
+    };
+
+    for part in parts {
+        println!("\n=== Part {} ===", part);
+        let start = Instant::now();
+        let params = Params::resolve(solution.params(), *part, &overrides)?;
+        let result = solution.solve(parsed.as_ref(), *part, &params)?;
+        println!("took {:?}.", start.elapsed());
+        match result {
+            Answer::Grid(_) => println!("Result =\n{}", result),
+            _ => println!("Result = {}", result),
+        }
+        // Known answers are for the real puzzle input and parameters, not whatever else was
+        // passed in
+        if input.is_default(solution.day()) && overrides.is_empty() {
+            println!(
+                "Known answer: {}",
+                known.check(solution.day(), *part, &result)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Solves every part of the day that has a known answer and reports how each compares.
+/// Parts without a known answer are reported as unknown without being run.
+pub fn verify(solution: &dyn AnySolution, known: &KnownAnswers) -> Result<Vec<(Part, Verdict)>> {
+    let parts = [Part::One, Part::Two];
+    if parts
+        .iter()
+        .all(|part| known.get(solution.day(), *part).is_none())
+    {
+        return Ok(parts.map(|part| (part, Verdict::Unknown)).into());
+    }
+
+    let input = InputSource::default_for(solution.day()).parse(solution)?;
+    parts
+        .iter()
+        .map(|part| {
+            let verdict = match known.get(solution.day(), *part) {
+                Some(_) => {
+                    let params = Params::resolve(solution.params(), *part, &BTreeMap::new())?;
+                    let answer = solution.solve(input.as_ref(), *part, &params)?;
+                    known.check(solution.day(), *part, &answer)
+                }
+                None => Verdict::Unknown,
+            };
+            Ok((*part, verdict))
+        })
+        .collect()
+}
@@ -0,0 +1,137 @@
+//! Sets up a new day: its source file from the template, its registration in the list of days
+//! and a file for the puzzle's example.
+
+use anyhow::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = "src/days/template.rs";
+const DAYS_MOD: &str = "src/days/mod.rs";
+
+/// Replaces the template's `const DAY: u8 = 0; // TODO: Fill the day` line.
+const TEMPLATE_DAY: &str = "const DAY: u8 = 0; // TODO: Fill the day";
+
+const EXAMPLE_STUB: &str = "\
+# Paste the puzzle's example below the --- line, then fill in the answers it should give.
+# part1 = \"\"
+# part2 = \"\"
+---
+";
+
+/// The files a new day was given, relative to the repository root.
+#[derive(Debug)]
+pub struct Scaffold {
+    pub source: PathBuf,
+    pub example: PathBuf,
+}
+
+/// Creates day `day` in the repository at `root`, refusing to touch a day that already exists.
+pub fn scaffold(root: &Path, day: u8) -> Result<Scaffold> {
+    ensure!(
+        (1..=25).contains(&day),
+        "Advent of Code only has days 1 to 25"
+    );
+
+    let module = format!("day{:02}", day);
+    let source = Path::new("src/days").join(format!("{}.rs", module));
+    let example = crate::examples_dir(day).join("1.txt");
+    for path in [&source, &example] {
+        ensure!(
+            !root.join(path).exists(),
+            "{} already exists, so day {} has already been started",
+            path.display(),
+            day
+        );
+    }
+
+    let template = fs::read_to_string(root.join(TEMPLATE))
+        .with_context(|| format!("Could not read {}", TEMPLATE))?;
+    ensure!(
+        template.contains(TEMPLATE_DAY),
+        "{} no longer has the line {:?} to fill in",
+        TEMPLATE,
+        TEMPLATE_DAY
+    );
+    let code = template
+        .replace(TEMPLATE_DAY, &format!("const DAY: u8 = {};", day))
+        .replace("DayNN", &format!("Day{:02}", day));
+
+    let days_mod = fs::read_to_string(root.join(DAYS_MOD))
+        .with_context(|| format!("Could not read {}", DAYS_MOD))?;
+    let days_mod = register(&days_mod, &module, day)?;
+
+    fs::write(root.join(&source), code)?;
+    fs::write(root.join(DAYS_MOD), days_mod)?;
+    fs::create_dir_all(root.join(crate::examples_dir(day)))?;
+    fs::write(root.join(&example), EXAMPLE_STUB)?;
+
+    Ok(Scaffold { source, example })
+}
+
+/// Adds `dayNN::DayNN` to the `days!` list, keeping it in order of day.
+fn register(days_mod: &str, module: &str, day: u8) -> Result<String> {
+    let entry = format!("    {}::Day{:02},", module, day);
+    let mut lines: Vec<&str> = days_mod.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| *line == "days! {")
+        .with_context(|| format!("No `days! {{` list in {}", DAYS_MOD))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "}")
+            .with_context(|| format!("The `days!` list in {} isn't closed", DAYS_MOD))?;
+
+    let registered = &lines[start + 1..end];
+    ensure!(
+        !registered.contains(&entry.as_str()),
+        "Day {} is already registered in {}",
+        day,
+        DAYS_MOD
+    );
+    // Entries are zero-padded, so they sort as text
+    let position = start + 1 + registered.partition_point(|line| *line < entry.as_str());
+    lines.insert(position, &entry);
+
+    let mut days_mod = lines.join("\n");
+    days_mod.push('\n');
+    Ok(days_mod)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_in_order() {
+        let days_mod = "days! {\n    day01::Day01,\n    day17::Day17,\n}\n\npub mod template;\n";
+        assert_eq!(
+            register(days_mod, "day16", 16).unwrap(),
+            "days! {\n    day01::Day01,\n    day16::Day16,\n    day17::Day17,\n}\n\npub mod template;\n"
+        );
+        assert!(register(days_mod, "day17", 17).is_err());
+    }
+
+    #[test]
+    fn creates_a_day_once() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        for file in [TEMPLATE, DAYS_MOD] {
+            fs::copy(file, root.join(file)).unwrap();
+        }
+
+        let created = scaffold(&root, 16).unwrap();
+        let code = fs::read_to_string(root.join(&created.source)).unwrap();
+        assert!(code.contains("pub struct Day16;"));
+        assert!(code.contains("const DAY: u8 = 16;\n"));
+        assert!(code.contains("check_examples(&Day16, &[Part::One])"));
+        let days_mod = fs::read_to_string(root.join(DAYS_MOD)).unwrap();
+        assert!(days_mod.contains("    day15::Day15,\n    day16::Day16,\n    day17::Day17,\n"));
+        let example = crate::Example::load(&root.join(&created.example)).unwrap();
+        assert_eq!((example.part1, example.input.as_str()), (None, ""));
+
+        assert!(scaffold(&root, 16).is_err());
+        assert!(scaffold(&root, 26).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
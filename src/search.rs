@@ -0,0 +1,288 @@
+//! Shortest-path searches over implicit graphs: the caller supplies a closure from a state to
+//! its neighbours, so any hashable state works, from a bare `Coord` to `(Coord, Direction)`.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// What a search found: the distance to every state it reached and, for each, every
+/// predecessor on a shortest path to it. Start states have no predecessors.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    pub distances: HashMap<S, usize>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// The first goal state reached, if any.
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    fn new(starts: impl IntoIterator<Item = S>) -> Self {
+        let mut result = Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        };
+        for start in starts {
+            result.distances.insert(start.clone(), 0);
+            result.predecessors.insert(start, Vec::new());
+        }
+        result
+    }
+
+    /// Records reaching `next` from `from` at `distance`, returning whether that's a new best.
+    fn relax(&mut self, from: &S, next: S, distance: usize) -> bool {
+        match self.distances.get(&next) {
+            Some(best) if *best < distance => false,
+            Some(best) if *best == distance => {
+                self.predecessors.get_mut(&next).unwrap().push(from.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// The distance to the goal, if one was reached.
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// One shortest path from a start state to `target`, both included.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap())?.first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// One shortest path from a start state to the goal.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// Every state on any shortest path from a start state to `target`.
+    pub fn on_shortest_paths(&self, target: &S) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut queue = vec![target.clone()];
+        while let Some(state) = queue.pop() {
+            if let Some(predecessors) = self.predecessors.get(&state) {
+                if seen.insert(state) {
+                    queue.extend(predecessors.iter().cloned());
+                }
+            }
+        }
+        seen
+    }
+
+    /// How many distinct shortest paths lead from the start states to `target`.
+    pub fn path_count(&self, target: &S) -> usize {
+        fn count<S: Clone + Eq + Hash>(
+            result: &SearchResult<S>,
+            state: &S,
+            memo: &mut HashMap<S, usize>,
+        ) -> usize {
+            if let Some(paths) = memo.get(state) {
+                return *paths;
+            }
+            let paths = match result.predecessors.get(state) {
+                None => 0,
+                Some(predecessors) if predecessors.is_empty() => 1,
+                Some(predecessors) => predecessors
+                    .iter()
+                    .map(|previous| count(result, previous, memo))
+                    .sum(),
+            };
+            memo.insert(state.clone(), paths);
+            paths
+        }
+
+        count(self, target, &mut HashMap::new())
+    }
+}
+
+/// Breadth-first search where every move costs one. Stops at the first goal, or explores
+/// everything reachable if no state is a goal.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new(starts);
+    let mut queue: VecDeque<_> = result.distances.keys().cloned().collect();
+
+    while let Some(state) = queue.pop_front() {
+        // Every state one step closer has already been expanded, so the goal's predecessors
+        // are complete by the time it comes off the queue.
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+        let distance = result.distances[&state];
+        for next in neighbors(&state) {
+            if result.relax(&state, next.clone(), distance + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm over non-negative move costs.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, neighbors, |_| 0, is_goal)
+}
+
+/// A* search over non-negative move costs. `heuristic` must never overestimate the remaining
+/// cost, and must be consistent (never drop by more than a move costs) for the set of all
+/// shortest paths to be complete.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut result = SearchResult::new(starts);
+    // The heap orders by estimate then distance; states live in `queued` so they needn't be `Ord`
+    let mut queued: Vec<S> = result.distances.keys().cloned().collect();
+    let mut queue: BinaryHeap<_> = queued
+        .iter()
+        .enumerate()
+        .map(|(i, state)| Reverse((heuristic(state), 0, i)))
+        .collect();
+    let mut settled = HashSet::new();
+
+    while let Some(Reverse((estimate, distance, i))) = queue.pop() {
+        if result
+            .goal_distance()
+            .is_some_and(|goal_distance| estimate > goal_distance)
+        {
+            break;
+        }
+        // Keep going past the goal until nothing left could tie with it, as zero-cost moves
+        // can still add equally short routes into it.
+        let state = queued[i].clone();
+        if distance > result.distances[&state] || !settled.insert(state.clone()) {
+            continue;
+        }
+        if result.goal.is_none() && is_goal(&state) {
+            result.goal = Some(state);
+            continue;
+        }
+        for (next, cost) in neighbors(&state) {
+            let next_distance = distance + cost;
+            if result.relax(&state, next.clone(), next_distance) {
+                let estimate = next_distance + heuristic(&next);
+                queue.push(Reverse((estimate, next_distance, queued.len())));
+                queued.push(next);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    const MAZE: &str = "\
+S...
+.#.#
+...E
+";
+
+    fn maze() -> (Grid<char>, Coord, Coord) {
+        let grid = Grid::from_reader(MAZE.as_bytes(), |chr| chr).unwrap();
+        let start = grid.find('S').unwrap();
+        let end = grid.find('E').unwrap();
+        (grid, start, end)
+    }
+
+    #[test]
+    fn bfs_finds_every_shortest_path() {
+        let (grid, start, end) = maze();
+        let result = bfs(
+            [start],
+            |coord| grid.neighbors4(*coord).filter(|next| grid[*next] != '#'),
+            |coord| *coord == end,
+        );
+
+        assert_eq!(result.goal_distance(), Some(5));
+        let path = result.path().unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), (start, end));
+        // Across the top and down the left
+        assert_eq!(result.path_count(&end), 2);
+        assert_eq!(result.on_shortest_paths(&end).len(), 9);
+    }
+
+    #[test]
+    fn bfs_explores_everything_without_a_goal() {
+        let (grid, start, _) = maze();
+        let result = bfs(
+            [start],
+            |coord| grid.neighbors4(*coord).filter(|next| grid[*next] != '#'),
+            |_| false,
+        );
+        assert_eq!(result.goal, None);
+        assert_eq!(result.distances.len(), 10);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree_on_turn_costs() {
+        // Moving forward costs 1 and turning costs 1000, as in a reindeer maze
+        let (grid, start, end) = maze();
+        let moves = |&(coord, dir): &(Coord, Direction)| {
+            let mut moves = vec![
+                ((coord, dir.turn_left()), 1000),
+                ((coord, dir.turn_right()), 1000),
+            ];
+            let ahead = coord + dir.to_delta();
+            if grid.get(ahead).is_some_and(|chr| *chr != '#') {
+                moves.push(((ahead, dir), 1));
+            }
+            moves
+        };
+        let starts = [(start, Direction::Right)];
+
+        let plain = dijkstra(starts, moves, |(coord, _)| *coord == end);
+        let guided = astar(
+            starts,
+            moves,
+            |(coord, _)| coord.manhattan(end),
+            |(coord, _)| *coord == end,
+        );
+
+        assert_eq!(plain.goal_distance(), Some(2005));
+        assert_eq!(guided.goal_distance(), Some(2005));
+        assert_eq!(plain.path_count(plain.goal.as_ref().unwrap()), 2);
+        assert_eq!(guided.path().unwrap().len(), 8);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{load_examples, Answer, Example, Param, Params, Rng};
+use anyhow::*;
+use std::any::Any;
+use std::fmt::Display;
+use std::io::BufRead;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// One day's puzzle. The input is parsed once and shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+    /// Puzzle constants the parts read from their `Params` rather than hard-coding.
+    const PARAMS: &'static [Param] = &[];
+
+    type Input: 'static;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input, _params: &Params) -> Result<Answer> {
+        bail!("Part 2 is not solved yet")
+    }
+
+    /// A random but valid puzzle input, for seeing how the parts scale and finding inputs they
+    /// get wrong. `size` is whatever grows the puzzle: a grid's side, or a count of lines.
+    fn generate(_size: usize, _rng: &mut Rng) -> Result<String> {
+        bail!("Day {} has no input generator", Self::DAY)
+    }
+
+    /// A slow but plainly correct version of the parts, checked against them on small generated
+    /// inputs by `aoc diff`. `None` for days without one.
+    fn reference(_input: &Self::Input, _part: Part, _params: &Params) -> Option<Result<Answer>> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`] so every day can be driven from one list.
+pub trait AnySolution {
+    fn day(&self) -> u8;
+    fn examples(&self) -> Result<Vec<Example>>;
+    fn params(&self) -> &'static [Param];
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>>;
+    fn solve(&self, input: &dyn Any, part: Part, params: &Params) -> Result<Answer>;
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String>;
+    fn reference(&self, input: &dyn Any, part: Part, params: &Params) -> Option<Result<Answer>>;
+}
+
+impl<S: Solution> AnySolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn examples(&self) -> Result<Vec<Example>> {
+        load_examples(S::DAY)
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(reader)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part, params: &Params) -> Result<Answer> {
+        let input = downcast_input::<S>(input)?;
+        match part {
+            Part::One => S::part1(input, params),
+            Part::Two => S::part2(input, params),
+        }
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String> {
+        S::generate(size, rng)
+    }
+
+    fn reference(&self, input: &dyn Any, part: Part, params: &Params) -> Option<Result<Answer>> {
+        match downcast_input::<S>(input) {
+            Result::Ok(input) => S::reference(input, part, params),
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> Result<&S::Input> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("Input was not parsed for day {}", S::DAY))
+}